@@ -7,7 +7,15 @@ mod modules;
 
 use modules::{
     cipher::CipherModule,
+    conscience_review::{ConscienceReviewModule, PendingReview, ReviewReasoning},
+    correlation,
     ember::EmberModule,
+    explain::{self, SystemExplanation},
+    ipc::{self, CipherRequest, EmberRequest, KernelRequest},
+    kernel_api::KernelApiClient,
+    kernel_events::{KernelEventClient, KernelEventConfig},
+    kernel_supervisor::{KernelProcessStatus, KernelSupervisor},
+    offline_cache::{CachedSnapshot, OfflineCache},
     orchestrator::{
         OrchestratorModule, 
         invoke_orchestrator_task, 
@@ -22,44 +30,118 @@ use modules::{
         filesystem_delete_item,
     },
     security::SecurityModule,
+    session_crypto::SessionCrypto,
     state::AppState,
 };
-use tauri::{
-    Manager, State,
-    async_runtime::spawn,
-};
+use tauri::{Manager, State};
 use std::{
     sync::{Arc, Mutex},
     time::Duration,
 };
+use tokio_util::sync::CancellationToken;
 
-// Command handler for SSE initialization
+// Command handler for kernel event streaming
 #[tauri::command]
-async fn initialize_sse_connection(app_handle: tauri::AppHandle) -> Result<(), String> {
-    let app_handle_clone = app_handle.clone();
+async fn connect_kernel_events(
+    app_handle: tauri::AppHandle,
+    client: State<'_, KernelEventClient>,
+) -> Result<bool, String> {
+    // Streaming starts during setup; this makes sure it is running and reports its state
+    client.start(app_handle);
     
-    spawn(async move {
-        // Start SSE server using port from environment or default to 5001
-        let port = std::env::var("BACKEND_PORT").unwrap_or("5001".into());
-        let port = port.parse::<u16>().unwrap_or(5001);
-        let sse_server = modules::sse::start_server(port).await
-            .map_err(|e| format!("Failed to start SSE server: {}", e))?;
-            
-        // Store SSE server in app state for later use
-        app_handle_clone.manage(sse_server);
-        
-        Ok::<(), String>(())
-    });
+    Ok(client.is_connected())
+}
+
+// Kernel daemon supervision commands
+#[tauri::command]
+async fn start_kernel(
+    app_handle: tauri::AppHandle,
+    supervisor: State<'_, KernelSupervisor>,
+    correlation_id: Option<String>,
+) -> Result<KernelProcessStatus, String> {
+    correlation::scope_with(correlation_id, supervisor.start(app_handle)).await
+}
+
+#[tauri::command]
+async fn stop_kernel(
+    supervisor: State<'_, KernelSupervisor>,
+    correlation_id: Option<String>,
+) -> Result<KernelProcessStatus, String> {
+    correlation::scope_with(correlation_id, supervisor.stop()).await
+}
+
+#[tauri::command]
+async fn restart_kernel(
+    app_handle: tauri::AppHandle,
+    supervisor: State<'_, KernelSupervisor>,
+    correlation_id: Option<String>,
+) -> Result<KernelProcessStatus, String> {
+    correlation::scope_with(correlation_id, supervisor.restart(app_handle)).await
+}
+
+#[tauri::command]
+async fn get_kernel_status(
+    supervisor: State<'_, KernelSupervisor>,
+    correlation_id: Option<String>,
+) -> Result<KernelProcessStatus, String> {
+    correlation::scope_with(correlation_id, supervisor.status()).await
+}
+
+#[tauri::command]
+async fn get_kernel_output(
+    supervisor: State<'_, KernelSupervisor>,
+    limit: Option<usize>,
+) -> Result<Vec<String>, String> {
+    supervisor.recent_output(limit.unwrap_or(200))
+}
+
+// Offline cache command - serves the last known kernel state with staleness info
+#[tauri::command]
+async fn get_cached_state(
+    cache: State<'_, OfflineCache>,
+    kernel_events: State<'_, KernelEventClient>,
+    correlation_id: Option<String>,
+) -> Result<CachedSnapshot, String> {
+    correlation::scope_with(correlation_id, async { cache.snapshot(kernel_events.is_connected()) }).await
+}
+
+#[tauri::command]
+async fn explain_system_state(
+    state: State<'_, Arc<Mutex<AppState>>>,
+    api: State<'_, KernelApiClient>,
+    cache: State<'_, OfflineCache>,
+    kernel_events: State<'_, KernelEventClient>,
+    correlation_id: Option<String>,
+) -> Result<SystemExplanation, String> {
+    // Prefer the kernel's narrative; fall back to a local one built from cached state
+    if kernel_events.is_connected() {
+        match correlation::scope_with(correlation_id, explain::from_kernel(&api)).await {
+            Ok(explanation) => return Ok(explanation),
+            Err(e) => log::warn!("Kernel explanation unavailable, using local narrative: {}", e),
+        }
+    }
     
-    Ok(())
+    let snapshot = cache.snapshot(kernel_events.is_connected())?;
+    let app_state = state.lock().map_err(|_| "Failed to lock app state".to_string())?;
+    explain::narrate_local(&app_state.get_health_info()?, &snapshot)
 }
 
 // Cipher commands
 #[tauri::command]
 async fn analyze_cipher_pattern(
     state: State<'_, Arc<Mutex<AppState>>>,
+    session: State<'_, SessionCrypto>,
+    kernel_events: State<'_, KernelEventClient>,
     pattern: String,
+    correlation_id: Option<String>,
 ) -> Result<String, String> {
+    // Proxy to the kernel implementation; the local module serves offline use and
+    // setups where no encrypted session can be negotiated
+    if kernel_events.is_connected() && session.is_available().await {
+        let request = KernelRequest::Cipher(CipherRequest::AnalyzePattern { pattern });
+        return correlation::scope_with(correlation_id, ipc::call(&session, &request)).await;
+    }
+    
     let app_state = state.lock().map_err(|_| "Failed to lock app state".to_string())?;
     app_state.cipher.analyze_pattern(&pattern)
         .map_err(|e| e.to_string())
@@ -87,24 +169,83 @@ async fn decrypt_data(
         .map_err(|e| e.to_string())
 }
 
+// Encrypted state sync commands - payloads are sealed under the kernel session key
+#[tauri::command]
+async fn sync_secure_state(
+    session: State<'_, SessionCrypto>,
+    key: String,
+    value: serde_json::Value,
+    correlation_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    if key.is_empty() {
+        return Err("Key cannot be empty".to_string());
+    }
+    
+    let body = serde_json::json!({ "key": key, "value": value });
+    correlation::scope_with(correlation_id, session.post_sealed("/v1/state/secure", &body)).await
+}
+
+#[tauri::command]
+async fn fetch_secure_state(
+    session: State<'_, SessionCrypto>,
+    key: String,
+    correlation_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    if key.is_empty() {
+        return Err("Key cannot be empty".to_string());
+    }
+    
+    let body = serde_json::json!({ "key": key });
+    correlation::scope_with(correlation_id, session.post_sealed("/v1/state/secure/fetch", &body)).await
+}
+
 // Ember unit commands
 #[tauri::command]
 async fn activate_ember_unit(
     state: State<'_, Arc<Mutex<AppState>>>,
-    parameters: String
+    session: State<'_, SessionCrypto>,
+    kernel_events: State<'_, KernelEventClient>,
+    parameters: String,
+    correlation_id: Option<String>,
 ) -> Result<String, String> {
-    let app_state = state.lock().map_err(|_| "Failed to lock app state".to_string())?;
-    app_state.ember.activate(&parameters)
+    if kernel_events.is_connected() && session.is_available().await {
+        let request = KernelRequest::Ember(EmberRequest::Activate {
+            parameters: ipc::parse_params(&parameters)?,
+        });
+        let result = correlation::scope_with(correlation_id, ipc::call(&session, &request)).await?;
+        
+        // Mirror the activation locally so health reporting and offline use stay in step
+        let mut app_state = state.lock().map_err(|_| "Failed to lock app state".to_string())?;
+        app_state.ember.activate(&parameters)?;
+        
+        return Ok(result);
+    }
+    
+    // Replayed to the kernel once it is reachable again
+    let mut app_state = state.lock().map_err(|_| "Failed to lock app state".to_string())?;
+    app_state.ember.activate_offline(&parameters)
         .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 async fn execute_ember_operation(
     state: State<'_, Arc<Mutex<AppState>>>,
+    session: State<'_, SessionCrypto>,
+    kernel_events: State<'_, KernelEventClient>,
     operation: String, 
-    params: String
+    params: String,
+    correlation_id: Option<String>,
 ) -> Result<String, String> {
-    let app_state = state.lock().map_err(|_| "Failed to lock app state".to_string())?;
+    // Operations may carry scan targets, so they travel over the encrypted session
+    if kernel_events.is_connected() && session.is_available().await {
+        let request = KernelRequest::Ember(EmberRequest::ExecuteOperation {
+            operation,
+            params: ipc::parse_params(&params)?,
+        });
+        return correlation::scope_with(correlation_id, ipc::call(&session, &request)).await;
+    }
+    
+    let mut app_state = state.lock().map_err(|_| "Failed to lock app state".to_string())?;
     app_state.ember.execute_operation(&operation, &params)
         .map_err(|e| e.to_string())
 }
@@ -119,6 +260,44 @@ async fn validate_memory_integrity(
         .map_err(|e| e.to_string())
 }
 
+// Conscience review queue commands
+#[tauri::command]
+async fn list_pending_reviews(
+    reviews: State<'_, ConscienceReviewModule>,
+    correlation_id: Option<String>,
+) -> Result<Vec<PendingReview>, String> {
+    correlation::scope_with(correlation_id, reviews.list_pending()).await
+}
+
+#[tauri::command]
+async fn get_review_reasoning(
+    reviews: State<'_, ConscienceReviewModule>,
+    review_id: String,
+    correlation_id: Option<String>,
+) -> Result<ReviewReasoning, String> {
+    correlation::scope_with(correlation_id, reviews.get_reasoning(&review_id)).await
+}
+
+#[tauri::command]
+async fn approve_review(
+    reviews: State<'_, ConscienceReviewModule>,
+    review_id: String,
+    notes: Option<String>,
+    correlation_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    correlation::scope_with(correlation_id, reviews.submit_verdict(&review_id, true, notes)).await
+}
+
+#[tauri::command]
+async fn reject_review(
+    reviews: State<'_, ConscienceReviewModule>,
+    review_id: String,
+    notes: Option<String>,
+    correlation_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    correlation::scope_with(correlation_id, reviews.submit_verdict(&review_id, false, notes)).await
+}
+
 // Phoenix ignition command - activates the system
 #[tauri::command]
 async fn ignite_phoenix(
@@ -146,21 +325,57 @@ async fn ignite_phoenix(
     Ok(health_data)
 }
 
-// SSE event emitter helper function 
-async fn emit_sse_event(app_handle: &tauri::AppHandle, event: &str, payload: &str) -> Result<(), String> {
-    let sse = app_handle.state::<modules::sse::SseServer>();
-    sse.emit_event(event, payload)
-        .map_err(|e| format!("Failed to emit SSE event: {}", e))
-}
-
 fn main() {
+    // Cancelled on exit so every background loop terminates with the app
+    let shutdown = CancellationToken::new();
+    let setup_shutdown = shutdown.clone();
+    
     tauri::Builder::default()
-        .setup(|app| {
+        .setup(move |app| {
+            let shutdown = setup_shutdown;
+            
             // Initialize shared app state
             let app_state = Arc::new(Mutex::new(AppState::new()));
             
             app.manage(app_state.clone());
             
+            // Kernel API client shared by the commands that proxy to the daemon
+            let kernel_api = KernelApiClient::from_env();
+            app.manage(ConscienceReviewModule::new(kernel_api.clone()));
+            app.manage(KernelSupervisor::from_env(kernel_api.clone(), shutdown.clone()));
+            app.manage(SessionCrypto::new(kernel_api.clone(), Duration::from_secs(15 * 60)));
+            app.manage(kernel_api);
+            
+            // Optionally launch (or attach to) the kernel daemon with the app
+            if matches!(std::env::var("PHOENIX_CORE_AUTOSTART").as_deref(), Ok("1") | Ok("true")) {
+                let app_handle = app.handle();
+                let autostart_shutdown = shutdown.clone();
+                tauri::async_runtime::spawn(async move {
+                    let supervisor = app_handle.state::<KernelSupervisor>();
+                    tokio::select! {
+                        _ = autostart_shutdown.cancelled() => {}
+                        result = supervisor.start(app_handle.clone()) => if let Err(e) = result {
+                            log::error!("Failed to start kernel daemon: {}", e);
+                        },
+                    }
+                });
+            }
+            
+            // Local cache of kernel state so the UI keeps working while the daemon is down
+            let offline_cache = match app.path_resolver().app_data_dir() {
+                Some(dir) => OfflineCache::open(&dir),
+                None => OfflineCache::temporary(),
+            }.or_else(|e| {
+                log::error!("{}; falling back to a temporary cache", e);
+                OfflineCache::temporary()
+            })?;
+            app.manage(offline_cache);
+            
+            // Subscribe to the kernel event stream and re-emit its events to the webview
+            let kernel_events = KernelEventClient::new(KernelEventConfig::from_env(), shutdown.clone());
+            kernel_events.start(app.handle());
+            app.manage(kernel_events);
+            
             // Initialize OrchestratorAgent in a background task
             let app_state_clone = app_state.clone();
             tauri::async_runtime::spawn(async move {
@@ -180,33 +395,48 @@ fn main() {
                 }
             });
             
-            // Background task for health monitoring
+            // Rotate the kernel session key periodically
             let app_handle = app.handle();
             tauri::async_runtime::spawn(async move {
+                let session = app_handle.state::<SessionCrypto>();
                 loop {
-                    // Emit health status event every 30 seconds
-                    let health_status = serde_json::json!({
-                        "status": "active",
-                        "timestamp": chrono::Utc::now().to_rfc3339()
-                    }).to_string();
-                    
-                    let _ = emit_sse_event(&app_handle, "health_status", &health_status).await;
+                    tokio::select! {
+                        _ = shutdown.cancelled() => break,
+                        _ = tokio::time::sleep(session.rotate_after()) => {}
+                    }
                     
-                    tokio::time::sleep(Duration::from_secs(30)).await;
+                    if let Err(e) = session.rotate().await {
+                        log::warn!("Failed to rotate kernel session key: {}", e);
+                    }
                 }
             });
             
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
-            initialize_sse_connection,
+            connect_kernel_events,
+            get_cached_state,
+            explain_system_state,
+            // Kernel daemon commands
+            start_kernel,
+            stop_kernel,
+            restart_kernel,
+            get_kernel_status,
+            get_kernel_output,
             analyze_cipher_pattern,
             encrypt_data,
             decrypt_data,
+            sync_secure_state,
+            fetch_secure_state,
             activate_ember_unit,
             execute_ember_operation,
             validate_memory_integrity,
             ignite_phoenix,
+            // Conscience review commands
+            list_pending_reviews,
+            get_review_reasoning,
+            approve_review,
+            reject_review,
             // Orchestrator commands
             invoke_orchestrator_task,
             submit_reviewed_task,
@@ -220,6 +450,18 @@ fn main() {
             filesystem_create_file,
             filesystem_delete_item,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(move |app_handle, event| {
+            if let tauri::RunEvent::Exit = event {
+                // The process exits right after this handler, so spawned tasks may never
+                // get to kill the daemon; stop it here before cancelling everything else
+                if let Some(supervisor) = app_handle.try_state::<KernelSupervisor>() {
+                    if let Err(e) = tauri::async_runtime::block_on(supervisor.terminate()) {
+                        log::error!("Failed to stop kernel daemon on exit: {}", e);
+                    }
+                }
+                shutdown.cancel();
+            }
+        });
 }
\ No newline at end of file
@@ -0,0 +1,127 @@
+use serde::{Serialize, Deserialize};
+use super::kernel_api::KernelApiClient;
+
+/// A conscience decision parked in the kernel awaiting human review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingReview {
+    pub id: String,
+    pub action: String,
+    pub requested_at: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Reasoning reported by a single conscience component (Id, Ego or Super-Ego)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentReasoning {
+    pub component: String,
+    pub approve: bool,
+    pub confidence: f32,
+    pub reasoning: String,
+}
+
+/// Structured reasoning behind a pending decision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewReasoning {
+    pub id: String,
+    pub action: String,
+    pub components: Vec<ComponentReasoning>,
+    pub consensus_approve: bool,
+    pub consensus_confidence: f32,
+    #[serde(default)]
+    pub violated_constraints: Vec<String>,
+}
+
+/// Human verdict submitted for a pending decision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewVerdict {
+    pub approved: bool,
+    pub reviewer: String,
+    pub notes: Option<String>,
+    pub timestamp: String,
+}
+
+/// ConscienceReviewModule exposes the kernel's conscience review queue
+/// so ethical reviews go through the same desktop flow as orchestrator tasks
+pub struct ConscienceReviewModule {
+    api: KernelApiClient,
+}
+
+impl ConscienceReviewModule {
+    /// Create a new ConscienceReviewModule instance
+    pub fn new(api: KernelApiClient) -> Self {
+        Self { api }
+    }
+
+    /// List decisions awaiting human review
+    pub async fn list_pending(&self) -> Result<Vec<PendingReview>, String> {
+        self.api.get("/v1/conscience/reviews").await
+    }
+
+    /// Fetch the component-level reasoning for a pending decision
+    pub async fn get_reasoning(&self, review_id: &str) -> Result<ReviewReasoning, String> {
+        validate_review_id(review_id)?;
+
+        self.api.get(&format!("/v1/conscience/reviews/{}", review_id)).await
+    }
+
+    /// Submit a verdict as the signed-in OS user; the kernel records it as the
+    /// decision outcome in TriuneConscience
+    pub async fn submit_verdict(
+        &self,
+        review_id: &str,
+        approved: bool,
+        notes: Option<String>,
+    ) -> Result<serde_json::Value, String> {
+        validate_review_id(review_id)?;
+
+        let verdict = ReviewVerdict {
+            approved,
+            reviewer: local_reviewer()?,
+            notes,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        };
+
+        self.api.post(&format!("/v1/conscience/reviews/{}/verdict", review_id), &verdict).await
+    }
+}
+
+/// Review ids come from the webview and end up in the URL path, so only plain
+/// identifier characters are accepted
+fn validate_review_id(review_id: &str) -> Result<(), String> {
+    if review_id.is_empty() {
+        return Err("Review ID cannot be empty".to_string());
+    }
+
+    if !review_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(format!("Invalid review ID: {}", review_id));
+    }
+
+    Ok(())
+}
+
+/// Identity of the approver: the OS account the app process runs as, looked up
+/// from the system rather than from environment variables the launcher controls
+fn local_reviewer() -> Result<String, String> {
+    Some(whoami::username())
+        .filter(|user| !user.is_empty())
+        .ok_or_else(|| "Unable to determine the reviewing OS user".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn review_ids_accept_plain_identifiers() {
+        assert!(validate_review_id("3f2b8c1e-9d4a-4c7e-8f00-1a2b3c4d5e6f").is_ok());
+        assert!(validate_review_id("review_42").is_ok());
+    }
+
+    #[test]
+    fn review_ids_reject_path_injection() {
+        for id in ["", "..", "../../state/secure/fetch", "a/b", "a?b=1", "a#b", "a%2Fb", "a.b", "a b"] {
+            assert!(validate_review_id(id).is_err(), "accepted {:?}", id);
+        }
+    }
+}
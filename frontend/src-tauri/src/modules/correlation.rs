@@ -0,0 +1,34 @@
+use std::future::Future;
+
+/// Header carrying the correlation id on every kernel API request
+pub const CORRELATION_HEADER: &str = "X-Correlation-Id";
+
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+/// Generate a new correlation id for a user action
+pub fn new_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Correlation id of the action currently being handled, if any
+pub fn current() -> Option<String> {
+    CORRELATION_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Run a background action under a fresh correlation id so every kernel call
+/// it makes can be traced end-to-end
+pub async fn scope<F: Future>(action: F) -> F::Output {
+    scope_with(None, action).await
+}
+
+/// Run a user action under the correlation id the webview generated for it,
+/// falling back to a fresh id when none (or an invalid one) was sent
+pub async fn scope_with<F: Future>(id: Option<String>, action: F) -> F::Output {
+    let id = id
+        .filter(|id| uuid::Uuid::parse_str(id).is_ok())
+        .unwrap_or_else(new_id);
+
+    CORRELATION_ID.scope(id, action).await
+}
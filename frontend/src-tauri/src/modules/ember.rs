@@ -24,6 +24,7 @@ pub struct EmberModule {
     active: bool,
     operations: HashMap<String, OperationResult>,
     current_engagement: Option<String>,
+    pending_activation: Option<String>,
 }
 
 impl EmberModule {
@@ -33,6 +34,7 @@ impl EmberModule {
             active: false,
             operations: HashMap::new(),
             current_engagement: None,
+            pending_activation: None,
         }
     }
     
@@ -53,6 +55,7 @@ impl EmberModule {
         
         // Set module as active
         self.active = true;
+        self.pending_activation = None;
         
         // Create activation result
         let result = serde_json::json!({
@@ -66,6 +69,26 @@ impl EmberModule {
             .map_err(|e| format!("Failed to serialize activation result: {}", e))
     }
     
+    /// Activate locally while the kernel is unreachable; the activation is
+    /// replayed to the kernel once it reconnects
+    pub fn activate_offline(&mut self, parameters: &str) -> Result<String, String> {
+        let result = self.activate(parameters)?;
+        self.pending_activation = Some(parameters.to_string());
+        Ok(result)
+    }
+    
+    /// Parameters of an offline activation the kernel has not seen yet
+    pub fn pending_activation(&self) -> Option<String> {
+        self.pending_activation.clone().filter(|_| self.active)
+    }
+    
+    /// Mark an offline activation as replayed, unless a newer activation replaced it meanwhile
+    pub fn clear_pending_activation(&mut self, parameters: &str) {
+        if self.pending_activation.as_deref() == Some(parameters) {
+            self.pending_activation = None;
+        }
+    }
+    
     /// Execute an Ember Unit operation
     pub fn execute_operation(&mut self, operation: &str, params: &str) -> Result<String, String> {
         if !self.active {
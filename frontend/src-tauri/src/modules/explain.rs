@@ -0,0 +1,207 @@
+use serde::{Serialize, Deserialize};
+use super::{
+    kernel_api::KernelApiClient,
+    offline_cache::CachedSnapshot,
+};
+
+/// Where a system explanation was produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExplanationSource {
+    Kernel,
+    Local,
+}
+
+/// Natural-language narrative of the current system state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemExplanation {
+    pub narrative: String,
+    pub source: ExplanationSource,
+    pub generated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct KernelNarrative {
+    narrative: String,
+}
+
+/// Fetch the kernel's narrative from /v1/explain
+pub async fn from_kernel(api: &KernelApiClient) -> Result<SystemExplanation, String> {
+    let response: KernelNarrative = api.get("/v1/explain").await?;
+
+    Ok(SystemExplanation {
+        narrative: response.narrative,
+        source: ExplanationSource::Kernel,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+/// Build a template-based narrative from the local modules' health report
+/// (as returned by `AppState::get_health_info`) and cached kernel state
+pub fn narrate_local(health_info: &str, snapshot: &CachedSnapshot) -> Result<SystemExplanation, String> {
+    let health: serde_json::Value = serde_json::from_str(health_info)
+        .map_err(|e| format!("Failed to parse health data: {}", e))?;
+    let mut sentences = Vec::new();
+
+    // Kernel connectivity and cached state
+    if snapshot.online {
+        sentences.push("The kernel daemon is connected.".to_string());
+    } else {
+        match &snapshot.last_sync {
+            Some(last_sync) => sentences.push(format!(
+                "The kernel daemon is unreachable; showing cached state last synced at {}.",
+                last_sync
+            )),
+            None => sentences.push(
+                "The kernel daemon is unreachable and no cached state is available yet.".to_string()
+            ),
+        }
+    }
+
+    if let Some(entry) = &snapshot.health {
+        let status = entry.data.get("status").and_then(|v| v.as_str()).unwrap_or("unknown");
+        sentences.push(format!(
+            "The last kernel health report, {} ago, was \"{}\"{}.",
+            count(entry.age_seconds.max(0) as usize, "second", "seconds"),
+            status,
+            if snapshot.stale { " and may be out of date" } else { "" }
+        ));
+    }
+
+    if !snapshot.decisions.is_empty() || !snapshot.scans.is_empty() {
+        sentences.push(format!(
+            "{} and {} are on record.",
+            count(snapshot.decisions.len(), "recent conscience decision", "recent conscience decisions"),
+            count(snapshot.scans.len(), "scan update", "scan updates")
+        ));
+    }
+
+    if let Some(latest) = snapshot.alerts.first() {
+        sentences.push(format!(
+            "{} {} raised recently; the latest, {} ago: {}.",
+            count(snapshot.alerts.len(), "alert", "alerts"),
+            if snapshot.alerts.len() == 1 { "was" } else { "were" },
+            count(latest.age_seconds.max(0) as usize, "second", "seconds"),
+            alert_summary(&latest.data)
+        ));
+    }
+
+    // Local modules
+    let ember = module_status(&health, "ember_status");
+    if ember.get("active").and_then(|v| v.as_bool()).unwrap_or(false) {
+        match ember.get("engagement").and_then(|v| v.as_str()) {
+            Some(engagement) => sentences.push(format!("Ember Unit is active on engagement {}.", engagement)),
+            None => sentences.push("Ember Unit is active without an engagement.".to_string()),
+        }
+    } else {
+        sentences.push("Ember Unit is inactive.".to_string());
+    }
+
+    let orchestrator = module_status(&health, "orchestrator_status");
+    if orchestrator.get("initialized").and_then(|v| v.as_bool()).unwrap_or(false) {
+        sentences.push("The orchestrator is running.".to_string());
+    } else {
+        sentences.push("The orchestrator has not been initialized.".to_string());
+    }
+
+    let cipher = module_status(&health, "cipher_status");
+    let security = module_status(&health, "security_status");
+    sentences.push(format!(
+        "The cipher module tracks {} and the security module holds {}.",
+        count(cipher.get("pattern_count").and_then(|v| v.as_u64()).unwrap_or(0) as usize, "pattern", "patterns"),
+        count(security.get("memory_entries").and_then(|v| v.as_u64()).unwrap_or(0) as usize, "memory entry", "memory entries")
+    ));
+
+    Ok(SystemExplanation {
+        narrative: sentences.join(" "),
+        source: ExplanationSource::Local,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+/// A count with the noun form that agrees with it, e.g. "1 alert" or "3 alerts"
+fn count(n: usize, singular: &str, plural: &str) -> String {
+    format!("{} {}", n, if n == 1 { singular } else { plural })
+}
+
+/// Module status from the health data; some modules report it as a JSON string
+fn module_status(health: &serde_json::Value, key: &str) -> serde_json::Value {
+    match health.get(key) {
+        Some(serde_json::Value::String(status)) => serde_json::from_str(status).unwrap_or_default(),
+        Some(status) => status.clone(),
+        None => serde_json::Value::Null,
+    }
+}
+
+/// Short human-readable description of an alert payload
+fn alert_summary(alert: &serde_json::Value) -> String {
+    ["message", "title", "summary"]
+        .iter()
+        .find_map(|key| alert.get(*key).and_then(|v| v.as_str()))
+        .or_else(|| alert.as_str())
+        .map(|text| format!("\"{}\"", text.trim_end_matches('.')))
+        .unwrap_or_else(|| "no details were given".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::offline_cache::CachedEntry;
+
+    fn entry(data: serde_json::Value, age_seconds: i64) -> CachedEntry {
+        CachedEntry {
+            data,
+            cached_at: chrono::Utc::now().to_rfc3339(),
+            age_seconds,
+        }
+    }
+
+    fn health_info() -> String {
+        serde_json::json!({
+            "cipher_status": { "pattern_count": 1 },
+            "ember_status": "{\"active\":true,\"engagement\":\"eng-7\"}",
+            "orchestrator_status": { "initialized": true },
+            "security_status": { "memory_entries": 4 },
+        }).to_string()
+    }
+
+    #[test]
+    fn narrates_single_items_in_the_singular() {
+        let snapshot = CachedSnapshot {
+            online: true,
+            decisions: vec![entry(serde_json::json!({}), 5)],
+            alerts: vec![entry(serde_json::json!({ "message": "Scope exceeded." }), 1)],
+            ..CachedSnapshot::default()
+        };
+
+        let narrative = narrate_local(&health_info(), &snapshot).unwrap().narrative;
+        assert!(narrative.contains("1 recent conscience decision and 0 scan updates are on record."), "{}", narrative);
+        assert!(narrative.contains("1 alert was raised recently; the latest, 1 second ago: \"Scope exceeded\"."), "{}", narrative);
+        assert!(narrative.contains("Ember Unit is active on engagement eng-7."), "{}", narrative);
+        assert!(narrative.contains("tracks 1 pattern and the security module holds 4 memory entries."), "{}", narrative);
+    }
+
+    #[test]
+    fn narrates_counts_in_the_plural() {
+        let snapshot = CachedSnapshot {
+            decisions: vec![entry(serde_json::json!({}), 5); 2],
+            scans: vec![entry(serde_json::json!({}), 5); 3],
+            alerts: vec![entry(serde_json::json!("Kernel restarted"), 30); 2],
+            ..CachedSnapshot::default()
+        };
+
+        let narrative = narrate_local(&health_info(), &snapshot).unwrap().narrative;
+        assert!(narrative.contains("2 recent conscience decisions and 3 scan updates are on record."), "{}", narrative);
+        assert!(narrative.contains("2 alerts were raised recently; the latest, 30 seconds ago: \"Kernel restarted\"."), "{}", narrative);
+    }
+
+    #[test]
+    fn narrates_an_unreachable_kernel_without_cached_state() {
+        let explanation = narrate_local("{}", &CachedSnapshot::default()).unwrap();
+
+        assert_eq!(explanation.source, ExplanationSource::Local);
+        assert!(explanation.narrative.starts_with("The kernel daemon is unreachable and no cached state is available yet."));
+        assert!(explanation.narrative.contains("Ember Unit is inactive."));
+        assert!(!explanation.narrative.contains("on record"));
+    }
+}
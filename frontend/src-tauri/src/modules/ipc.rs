@@ -0,0 +1,125 @@
+use serde::{Serialize, Deserialize};
+use super::session_crypto::SessionCrypto;
+
+/// Kernel endpoint that dispatches typed module requests
+const IPC_PATH: &str = "/v1/ipc";
+
+/// Requests handled by the kernel's cipher-guard implementation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum CipherRequest {
+    AnalyzePattern { pattern: String },
+}
+
+/// Requests handled by the kernel's ember-unit implementation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum EmberRequest {
+    Activate { parameters: serde_json::Value },
+    ExecuteOperation { operation: String, params: serde_json::Value },
+}
+
+/// A request from a frontend module to its kernel counterpart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "module", content = "request", rename_all = "snake_case")]
+pub enum KernelRequest {
+    Cipher(CipherRequest),
+    Ember(EmberRequest),
+}
+
+/// The kernel's reply to a KernelRequest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum KernelResponse {
+    Ok { result: serde_json::Value },
+    Error { message: String },
+}
+
+/// Send a typed request to the kernel over the encrypted session channel
+/// and return its result serialized the same way the local modules do
+pub async fn call(session: &SessionCrypto, request: &KernelRequest) -> Result<String, String> {
+    let response: KernelResponse = session.post_sealed(IPC_PATH, request).await?;
+
+    match response {
+        KernelResponse::Ok { result: serde_json::Value::String(result) } => Ok(result),
+        KernelResponse::Ok { result } => serde_json::to_string(&result)
+            .map_err(|e| format!("Failed to serialize kernel result: {}", e)),
+        KernelResponse::Error { message } => Err(message),
+    }
+}
+
+/// Replay an Ember Unit activation made while the kernel was unreachable so the
+/// kernel's unit is not left inactive after reconnecting
+pub async fn replay_ember_activation(session: &SessionCrypto, parameters: &str) -> Result<(), String> {
+    let request = KernelRequest::Ember(EmberRequest::Activate {
+        parameters: parse_params(parameters)?,
+    });
+
+    call(session, &request).await.map(|_| ())
+}
+
+/// Parse a JSON parameter string from the webview before it is sent to the kernel
+pub fn parse_params(params: &str) -> Result<serde_json::Value, String> {
+    if params.is_empty() {
+        return Err("Parameters cannot be empty".to_string());
+    }
+
+    serde_json::from_str(params)
+        .map_err(|e| format!("Failed to parse parameters: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn cipher_request_wire_format() {
+        let request = KernelRequest::Cipher(CipherRequest::AnalyzePattern { pattern: "abc".to_string() });
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({ "module": "cipher", "request": { "op": "analyze_pattern", "pattern": "abc" } })
+        );
+    }
+
+    #[test]
+    fn ember_request_wire_format() {
+        let activate = KernelRequest::Ember(EmberRequest::Activate {
+            parameters: json!({ "engagement_id": "op-7" }),
+        });
+        let execute = KernelRequest::Ember(EmberRequest::ExecuteOperation {
+            operation: "scan".to_string(),
+            params: json!({ "target": "10.0.0.1" }),
+        });
+
+        assert_eq!(
+            serde_json::to_value(&activate).unwrap(),
+            json!({ "module": "ember", "request": { "op": "activate", "parameters": { "engagement_id": "op-7" } } })
+        );
+        assert_eq!(
+            serde_json::to_value(&execute).unwrap(),
+            json!({
+                "module": "ember",
+                "request": { "op": "execute_operation", "operation": "scan", "params": { "target": "10.0.0.1" } }
+            })
+        );
+    }
+
+    #[test]
+    fn kernel_response_wire_format() {
+        let ok: KernelResponse = serde_json::from_value(json!({ "status": "ok", "result": { "score": 3 } })).unwrap();
+        let error: KernelResponse = serde_json::from_value(json!({ "status": "error", "message": "denied" })).unwrap();
+
+        assert!(matches!(ok, KernelResponse::Ok { result } if result == json!({ "score": 3 })));
+        assert!(matches!(error, KernelResponse::Error { message } if message == "denied"));
+        assert!(serde_json::from_value::<KernelResponse>(json!({ "status": "pending" })).is_err());
+    }
+
+    #[test]
+    fn parse_params_rejects_invalid_input() {
+        assert!(parse_params("").is_err());
+        assert!(parse_params("{not json").is_err());
+        assert_eq!(parse_params(r#"{"a":1}"#).unwrap(), json!({ "a": 1 }));
+    }
+}
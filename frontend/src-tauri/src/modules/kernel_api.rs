@@ -0,0 +1,140 @@
+use std::time::Duration;
+use serde::{de::DeserializeOwned, Serialize};
+use super::correlation::{self, CORRELATION_HEADER};
+
+/// Port of the kernel API, taken from the environment or defaulting to 5001
+pub fn kernel_port() -> u16 {
+    let port = std::env::var("BACKEND_PORT").unwrap_or("5001".into());
+    port.parse::<u16>().unwrap_or(5001)
+}
+
+/// Bearer token used to authenticate against the kernel API
+pub fn kernel_token() -> Option<String> {
+    std::env::var("PHOENIX_API_TOKEN").ok()
+}
+
+/// Whether a kernel URL is protected by TLS or stays on this machine; the API token
+/// is only ever sent to such URLs
+pub fn is_secure_url(url: &str) -> bool {
+    let Ok(url) = reqwest::Url::parse(url) else {
+        return false;
+    };
+
+    if matches!(url.scheme(), "https" | "wss") {
+        return true;
+    }
+
+    match url.host_str() {
+        Some("localhost") => true,
+        Some(host) => host.trim_start_matches('[').trim_end_matches(']')
+            .parse::<std::net::IpAddr>()
+            .map(|ip| ip.is_loopback())
+            .unwrap_or(false),
+        None => false,
+    }
+}
+
+/// KernelApiClient performs authenticated JSON requests against the
+/// phoenix-core daemon's HTTP API
+#[derive(Clone)]
+pub struct KernelApiClient {
+    client: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+}
+
+impl KernelApiClient {
+    /// Create a new KernelApiClient instance
+    pub fn new(base_url: &str, token: Option<String>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+
+        if token.is_some() && !is_secure_url(base_url) {
+            log::warn!("Kernel API token will not be sent to {} over plain http; use https", base_url);
+        }
+
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token,
+        }
+    }
+
+    /// Create a client for the local kernel using the environment configuration
+    pub fn from_env() -> Self {
+        let base_url = std::env::var("PHOENIX_KERNEL_URL")
+            .unwrap_or_else(|_| format!("http://127.0.0.1:{}", kernel_port()));
+
+        Self::new(&base_url, kernel_token())
+    }
+
+    /// Bearer token the client authenticates with, if configured
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Whether requests stay on this machine or are protected by TLS
+    pub fn is_secure_transport(&self) -> bool {
+        is_secure_url(&self.base_url)
+    }
+
+    /// Send a GET request and decode the JSON response
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+        let request = self.client.get(self.url(path));
+        self.send(request).await
+    }
+
+    /// Send a GET request with an additional header, such as a session id
+    pub async fn get_with_header<T: DeserializeOwned>(&self, path: &str, header: &str, value: &str) -> Result<T, String> {
+        let request = self.client.get(self.url(path)).header(header, value);
+        self.send(request).await
+    }
+
+    /// Send a POST request with a JSON body and decode the JSON response
+    pub async fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T, String> {
+        let request = self.client.post(self.url(path)).json(body);
+        self.send(request).await
+    }
+
+    /// Send a POST request with an additional header, such as a session id
+    pub async fn post_with_header<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        header: &str,
+        value: &str,
+        body: &B,
+    ) -> Result<T, String> {
+        let request = self.client.post(self.url(path)).header(header, value).json(body);
+        self.send(request).await
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder) -> Result<T, String> {
+        // Calls made outside a correlation scope still get an id of their own
+        let correlation_id = correlation::current().unwrap_or_else(correlation::new_id);
+        let request = request.header(CORRELATION_HEADER, &correlation_id);
+
+        // The token also keys the session handshake, so it never goes out in the clear
+        let request = match &self.token {
+            Some(token) if self.is_secure_transport() => request.bearer_auth(token),
+            _ => request,
+        };
+
+        let response = request.send().await
+            .map_err(|e| format!("Kernel API request failed [{}]: {}", correlation_id, e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("Kernel API returned {} [{}]: {}", status, correlation_id, body));
+        }
+
+        response.json::<T>().await
+            .map_err(|e| format!("Failed to parse kernel API response [{}]: {}", correlation_id, e))
+    }
+}
@@ -0,0 +1,349 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use futures_util::StreamExt;
+use serde::{Serialize, Deserialize};
+use tauri::{AppHandle, Manager};
+use super::{
+    correlation,
+    ipc,
+    kernel_api::{is_secure_url, kernel_port, kernel_token, KernelApiClient},
+    offline_cache::OfflineCache,
+    session_crypto::{SealedPayload, SessionCrypto, SESSION_HEADER},
+    state::AppState,
+};
+use tokio_util::sync::CancellationToken;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{client::IntoClientRequest, http::HeaderValue, Message},
+};
+
+/// Tauri event emitted whenever the kernel stream connects or drops
+pub const CONNECTION_EVENT: &str = "kernel:connection";
+
+/// Tauri event emitted once the offline cache has been refreshed after reconnecting
+pub const RESYNCED_EVENT: &str = "kernel:resynced";
+
+/// Typed events streamed from the kernel API
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "payload", rename_all = "snake_case")]
+pub enum KernelEvent {
+    HealthStatus(serde_json::Value),
+    ConscienceDecision(serde_json::Value),
+    /// A deliberation stage (request received, Id/Ego/Super-Ego voted, consensus reached)
+    DecisionProgress(serde_json::Value),
+    ScanUpdate(serde_json::Value),
+    Alert(serde_json::Value),
+}
+
+impl KernelEvent {
+    /// Name of the Tauri event this kernel event is re-emitted as
+    pub fn name(&self) -> &'static str {
+        match self {
+            KernelEvent::HealthStatus(_) => "kernel:health_status",
+            KernelEvent::ConscienceDecision(_) => "kernel:conscience_decision",
+            KernelEvent::DecisionProgress(_) => "kernel:decision_progress",
+            KernelEvent::ScanUpdate(_) => "kernel:scan_update",
+            KernelEvent::Alert(_) => "kernel:alert",
+        }
+    }
+
+    /// Event payload forwarded to the webview
+    pub fn payload(&self) -> &serde_json::Value {
+        match self {
+            KernelEvent::HealthStatus(payload)
+            | KernelEvent::ConscienceDecision(payload)
+            | KernelEvent::DecisionProgress(payload)
+            | KernelEvent::ScanUpdate(payload)
+            | KernelEvent::Alert(payload) => payload,
+        }
+    }
+}
+
+/// Connection settings for the kernel event stream
+#[derive(Clone, Debug)]
+pub struct KernelEventConfig {
+    pub url: String,
+    pub token: Option<String>,
+    pub reconnect_delay: Duration,
+}
+
+impl KernelEventConfig {
+    /// Build the configuration from the environment, defaulting to the kernel API port
+    pub fn from_env() -> Self {
+        Self {
+            url: std::env::var("PHOENIX_KERNEL_EVENTS_URL")
+                .unwrap_or_else(|_| format!("ws://127.0.0.1:{}/ws/events", kernel_port())),
+            token: kernel_token(),
+            reconnect_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Why a kernel event stream ended without an error
+enum StreamEnd {
+    /// The kernel closed the stream
+    Closed,
+    /// The session key rotated; the stream must be reopened under the new session
+    Rekeyed,
+}
+
+/// KernelEventClient subscribes to the kernel's authenticated WebSocket
+/// event stream and re-emits every event as a typed Tauri event.
+/// This replaces the standalone SSE server that collided with the kernel API port.
+pub struct KernelEventClient {
+    config: KernelEventConfig,
+    connected: Arc<AtomicBool>,
+    started: AtomicBool,
+    shutdown: CancellationToken,
+}
+
+impl KernelEventClient {
+    /// Create a new KernelEventClient instance that stops streaming on shutdown
+    pub fn new(config: KernelEventConfig, shutdown: CancellationToken) -> Self {
+        Self {
+            config,
+            connected: Arc::new(AtomicBool::new(false)),
+            started: AtomicBool::new(false),
+            shutdown,
+        }
+    }
+
+    /// Whether the stream is currently connected to the kernel
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// Start streaming in the background, reconnecting whenever the kernel drops.
+    /// Calling this more than once is a no-op.
+    pub fn start(&self, app_handle: AppHandle) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let config = self.config.clone();
+        let connected = self.connected.clone();
+        let shutdown = self.shutdown.clone();
+
+        tauri::async_runtime::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    result = stream_events(&config, &app_handle, &connected, &shutdown) => match result {
+                        // Reconnect straight away; the webview never sees the stream drop
+                        Ok(StreamEnd::Rekeyed) => {
+                            log::info!("Reopening kernel event stream under the rotated session");
+                            continue;
+                        }
+                        Ok(StreamEnd::Closed) => log::warn!("Kernel event stream closed"),
+                        Err(e) => log::warn!("Kernel event stream unavailable: {}", e),
+                    },
+                }
+
+                set_connected(&app_handle, &connected, false);
+
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = tokio::time::sleep(config.reconnect_delay) => {}
+                }
+            }
+
+            connected.store(false, Ordering::SeqCst);
+        });
+    }
+}
+
+/// Connect to the kernel and forward events until the stream ends
+async fn stream_events(
+    config: &KernelEventConfig,
+    app_handle: &AppHandle,
+    connected: &AtomicBool,
+    shutdown: &CancellationToken,
+) -> Result<StreamEnd, String> {
+    let mut request = config.url.as_str().into_client_request()
+        .map_err(|e| format!("Invalid kernel event URL {}: {}", config.url, e))?;
+
+    // Authenticate with the same bearer token the kernel API expects, but never in the clear
+    if let Some(token) = &config.token {
+        if !is_secure_url(&config.url) {
+            return Err(format!("Refusing to send the kernel API token to {} over plain ws; use wss", config.url));
+        }
+
+        let value = HeaderValue::from_str(&format!("Bearer {}", token))
+            .map_err(|_| "Kernel API token contains invalid characters".to_string())?;
+        request.headers_mut().insert("Authorization", value);
+    }
+
+    // Tell the kernel which session to seal scan updates under. Without one the
+    // connection is retried rather than opened with scan updates undeliverable.
+    let session = app_handle.try_state::<SessionCrypto>()
+        .ok_or_else(|| "Kernel session is not available".to_string())?;
+    let session_id = session.session_id().await
+        .map_err(|e| format!("Failed to establish kernel session: {}", e))?;
+    let mut rekeyed = session.subscribe();
+    if rekeyed.borrow_and_update().as_deref() != Some(session_id.as_str()) {
+        return Ok(StreamEnd::Rekeyed);
+    }
+
+    let value = HeaderValue::from_str(&session_id)
+        .map_err(|_| "Kernel session id contains invalid characters".to_string())?;
+    request.headers_mut().insert(SESSION_HEADER, value);
+
+    let (mut stream, _) = connect_async(request).await
+        .map_err(|e| format!("Failed to connect to {}: {}", config.url, e))?;
+
+    set_connected(app_handle, connected, true);
+    resync_cache(app_handle, shutdown.clone());
+    replay_module_state(app_handle, shutdown.clone());
+
+    loop {
+        let message = tokio::select! {
+            message = stream.next() => match message {
+                Some(message) => message,
+                None => break,
+            },
+            // The kernel keeps sealing under the announced session, which stops opening
+            // once it ages out of the current/previous pair
+            _ = rekeyed.changed() => return Ok(StreamEnd::Rekeyed),
+        };
+        let message = message
+            .map_err(|e| format!("Failed to read kernel event: {}", e))?;
+
+        match message {
+            Message::Text(text) => match serde_json::from_str::<KernelEvent>(&text) {
+                Ok(event) => {
+                    let Some(event) = unseal_event(app_handle, event).await else {
+                        continue;
+                    };
+
+                    if let Some(cache) = app_handle.try_state::<OfflineCache>() {
+                        if let Err(e) = cache.record_event(&event) {
+                            log::warn!("Failed to cache kernel event: {}", e);
+                        }
+                    }
+
+                    if let Err(e) = app_handle.emit_all(event.name(), event.payload()) {
+                        log::error!("Failed to emit {}: {}", event.name(), e);
+                    }
+                }
+                Err(e) => log::debug!("Ignoring unrecognised kernel event: {}", e),
+            },
+            Message::Close(_) => break,
+            _ => {}
+        }
+    }
+
+    Ok(StreamEnd::Closed)
+}
+
+/// Open scan updates, which the kernel seals under the session key since they
+/// carry targets. Scan updates that are not sealed are dropped.
+async fn unseal_event(app_handle: &AppHandle, event: KernelEvent) -> Option<KernelEvent> {
+    let KernelEvent::ScanUpdate(payload) = event else {
+        return Some(event);
+    };
+
+    let opened = match (serde_json::from_value::<SealedPayload>(payload), app_handle.try_state::<SessionCrypto>()) {
+        (Ok(sealed), Some(session)) => session.open(&sealed).await,
+        (Err(e), _) => Err(format!("scan update is not sealed: {}", e)),
+        (_, None) => Err("no kernel session available".to_string()),
+    };
+
+    match opened {
+        Ok(payload) => Some(KernelEvent::ScanUpdate(payload)),
+        Err(e) => {
+            log::warn!("Dropping scan update: {}", e);
+            None
+        }
+    }
+}
+
+/// Record the connection state and notify the webview when it changes
+fn set_connected(app_handle: &AppHandle, connected: &AtomicBool, value: bool) {
+    if connected.swap(value, Ordering::SeqCst) == value {
+        return;
+    }
+
+    let status = serde_json::json!({
+        "connected": value,
+        "timestamp": chrono::Utc::now().to_rfc3339(),
+    });
+
+    if let Err(e) = app_handle.emit_all(CONNECTION_EVENT, status) {
+        log::error!("Failed to emit kernel connection status: {}", e);
+    }
+}
+
+/// Refresh the offline cache from the kernel API in the background
+fn resync_cache(app_handle: &AppHandle, shutdown: CancellationToken) {
+    let app_handle = app_handle.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let (Some(cache), Some(api), Some(session)) = (
+            app_handle.try_state::<OfflineCache>(),
+            app_handle.try_state::<KernelApiClient>(),
+            app_handle.try_state::<SessionCrypto>(),
+        ) else {
+            return;
+        };
+
+        let resync = correlation::scope(async {
+            cache.resync(api.inner(), session.inner()).await.map(|()| correlation::current())
+        });
+        let resynced = tokio::select! {
+            _ = shutdown.cancelled() => return,
+            resynced = resync => resynced,
+        };
+
+        match resynced {
+            Ok(correlation_id) => {
+                let status = serde_json::json!({
+                    "correlation_id": correlation_id,
+                    "timestamp": chrono::Utc::now().to_rfc3339(),
+                });
+                let _ = app_handle.emit_all(RESYNCED_EVENT, status);
+            }
+            Err(e) => log::warn!("Failed to resync offline cache: {}", e),
+        }
+    });
+}
+
+/// Bring the kernel's modules in line with activations made locally while it was unreachable
+fn replay_module_state(app_handle: &AppHandle, shutdown: CancellationToken) {
+    let app_handle = app_handle.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let (Some(state), Some(session)) = (
+            app_handle.try_state::<Arc<Mutex<AppState>>>(),
+            app_handle.try_state::<SessionCrypto>(),
+        ) else {
+            return;
+        };
+
+        let parameters = match state.lock() {
+            Ok(state) => state.ember.pending_activation(),
+            Err(_) => return,
+        };
+        let Some(parameters) = parameters else {
+            return;
+        };
+
+        let replay = correlation::scope(ipc::replay_ember_activation(session.inner(), &parameters));
+        tokio::select! {
+            _ = shutdown.cancelled() => {}
+            result = replay => match result {
+                Ok(()) => {
+                    if let Ok(mut state) = state.lock() {
+                        state.ember.clear_pending_activation(&parameters);
+                    }
+                    log::info!("Replayed Ember Unit activation to the kernel");
+                }
+                Err(e) => log::warn!("Failed to replay Ember Unit activation: {}", e),
+            },
+        }
+    });
+}
@@ -0,0 +1,410 @@
+use std::{
+    collections::VecDeque,
+    process::Stdio,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use serde::{Serialize, Deserialize};
+use tauri::{AppHandle, Manager};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, BufReader},
+    process::Command,
+    sync::{oneshot, watch},
+};
+use tokio_util::sync::CancellationToken;
+use super::{correlation, kernel_api::KernelApiClient};
+
+/// Number of daemon output lines kept for the UI
+const MAX_OUTPUT_LINES: usize = 500;
+
+/// How long to wait for the daemon to exit after asking it to stop
+const STOP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often a running daemon is probed for responsiveness
+const HEALTH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How the app is related to the kernel daemon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KernelMode {
+    /// Started and owned by this app
+    Managed,
+    /// An existing daemon was already running and has been connected to
+    Attached,
+    /// A start is in progress
+    Starting,
+    Stopped,
+}
+
+/// Kernel daemon status reported to the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KernelProcessStatus {
+    pub mode: KernelMode,
+    pub pid: Option<u32>,
+    pub started_at: Option<String>,
+    pub healthy: bool,
+    pub restarts: u32,
+    pub last_exit: Option<String>,
+}
+
+/// A daemon child process started by the supervisor
+struct RunningKernel {
+    pid: Option<u32>,
+    started_at: String,
+    /// Taken by the first stop request
+    stop_tx: Option<oneshot::Sender<()>>,
+    /// Becomes true once the monitor task has finished with the child
+    done_rx: watch::Receiver<bool>,
+}
+
+struct SupervisorState {
+    running: Option<RunningKernel>,
+    starting: bool,
+    attached: bool,
+    restarts: u32,
+    last_exit: Option<String>,
+    output: VecDeque<String>,
+}
+
+/// Holds the `starting` reservation and releases it when the start finishes,
+/// fails or its future is dropped
+struct StartReservation(Arc<Mutex<SupervisorState>>);
+
+impl Drop for StartReservation {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.0.lock() {
+            state.starting = false;
+        }
+    }
+}
+
+/// KernelSupervisor starts, stops and restarts the phoenix-core daemon as a
+/// child process, streams its output and reports unexpected exits or hangs
+pub struct KernelSupervisor {
+    binary: String,
+    args: Vec<String>,
+    api: KernelApiClient,
+    state: Arc<Mutex<SupervisorState>>,
+    health_watch: AtomicBool,
+    shutdown: CancellationToken,
+}
+
+impl KernelSupervisor {
+    /// Create a new KernelSupervisor instance; a managed daemon is stopped on shutdown
+    pub fn new(binary: &str, args: Vec<String>, api: KernelApiClient, shutdown: CancellationToken) -> Self {
+        Self {
+            binary: binary.to_string(),
+            args,
+            api,
+            shutdown,
+            health_watch: AtomicBool::new(false),
+            state: Arc::new(Mutex::new(SupervisorState {
+                running: None,
+                starting: false,
+                attached: false,
+                restarts: 0,
+                last_exit: None,
+                output: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Create a supervisor for the daemon configured in the environment
+    pub fn from_env(api: KernelApiClient, shutdown: CancellationToken) -> Self {
+        let binary = std::env::var("PHOENIX_CORE_BIN").unwrap_or("phoenix-core".into());
+        let args = std::env::var("PHOENIX_CORE_ARGS")
+            .map(|args| args.split_whitespace().map(String::from).collect())
+            .unwrap_or_default();
+
+        Self::new(&binary, args, api, shutdown)
+    }
+
+    /// Start the daemon, or attach to one that is already answering on the API port
+    pub async fn start(&self, app_handle: AppHandle) -> Result<KernelProcessStatus, String> {
+        // Reserve the start before the first await so concurrent callers can't spawn twice
+        let reservation = {
+            let mut state = self.lock_state()?;
+            if state.running.is_some() || state.starting {
+                return Err("Kernel daemon is already running".to_string());
+            }
+            state.starting = true;
+            StartReservation(self.state.clone())
+        };
+
+        let launched = self.launch(app_handle.clone()).await;
+        drop(reservation);
+        launched?;
+
+        self.watch_health(app_handle);
+        self.status().await
+    }
+
+    /// Attach to a healthy daemon or spawn a new one
+    async fn launch(&self, app_handle: AppHandle) -> Result<(), String> {
+        if self.is_healthy().await {
+            self.lock_state()?.attached = true;
+            log::info!("Attached to an existing kernel daemon");
+            return Ok(());
+        }
+
+        let mut child = Command::new(&self.binary)
+            .args(&self.args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| format!("Failed to start {}: {}", self.binary, e))?;
+
+        if let Some(stdout) = child.stdout.take() {
+            self.forward_output(app_handle.clone(), "stdout", stdout);
+        }
+        if let Some(stderr) = child.stderr.take() {
+            self.forward_output(app_handle.clone(), "stderr", stderr);
+        }
+
+        let pid = child.id();
+        let (stop_tx, stop_rx) = oneshot::channel();
+        let (done_tx, done_rx) = watch::channel(false);
+
+        {
+            let mut state = self.lock_state()?;
+            state.attached = false;
+            state.running = Some(RunningKernel {
+                pid,
+                started_at: chrono::Utc::now().to_rfc3339(),
+                stop_tx: Some(stop_tx),
+                done_rx,
+            });
+        }
+
+        // Watch the child until it exits, a stop is requested or the app shuts down.
+        // Exit events carry the correlation id of the action that started the daemon.
+        let correlation_id = correlation::current();
+        let state = self.state.clone();
+        let shutdown = self.shutdown.clone();
+        tauri::async_runtime::spawn(async move {
+            let exit = tokio::select! {
+                status = child.wait() => Some(status),
+                _ = stop_rx => None,
+                _ = shutdown.cancelled() => None,
+            };
+
+            // The daemon only counts as gone once it has exited or been killed
+            let mut gone = true;
+            if exit.is_none() {
+                if let Err(e) = child.kill().await {
+                    log::error!("Failed to stop kernel daemon: {}", e);
+                    gone = false;
+                }
+            }
+
+            let event = match &exit {
+                Some(Ok(status)) if status.success() => "kernel:exited",
+                Some(_) => "kernel:crashed",
+                None => "kernel:stopped",
+            };
+            let description = match exit {
+                Some(Ok(status)) => status.to_string(),
+                Some(Err(e)) => format!("failed to wait for daemon: {}", e),
+                None => "stopped".to_string(),
+            };
+
+            if let Ok(mut state) = state.lock() {
+                state.last_exit = Some(description.clone());
+                if gone {
+                    state.running = None;
+                }
+            }
+
+            let payload = serde_json::json!({
+                "pid": pid,
+                "exit": description,
+                "correlation_id": correlation_id,
+                "timestamp": chrono::Utc::now().to_rfc3339(),
+            });
+            let _ = app_handle.emit_all(event, payload);
+
+            let _ = done_tx.send(true);
+        });
+
+        log::info!("Started kernel daemon {} (pid {:?})", self.binary, pid);
+        Ok(())
+    }
+
+    /// Stop a daemon started by this app; attached daemons are only detached
+    pub async fn stop(&self) -> Result<KernelProcessStatus, String> {
+        self.terminate().await?;
+        self.status().await
+    }
+
+    /// Kill a managed daemon and wait for it to exit, without probing the API afterwards.
+    /// The daemon stays recorded as running until it has actually exited, so a start
+    /// can't spawn a second one while the first is still shutting down.
+    pub async fn terminate(&self) -> Result<(), String> {
+        let (stop_tx, mut done_rx) = {
+            let mut state = self.lock_state()?;
+            state.attached = false;
+            match state.running.as_mut() {
+                Some(running) => (running.stop_tx.take(), running.done_rx.clone()),
+                None => return Ok(()),
+            }
+        };
+
+        if let Some(stop_tx) = stop_tx {
+            let _ = stop_tx.send(());
+        }
+
+        let exited = tokio::time::timeout(STOP_TIMEOUT, async {
+            while !*done_rx.borrow() {
+                if done_rx.changed().await.is_err() {
+                    break;
+                }
+            }
+        }).await;
+        if exited.is_err() {
+            return Err("Timed out waiting for the kernel daemon to exit".to_string());
+        }
+
+        if self.lock_state()?.running.is_some() {
+            return Err("Kernel daemon did not exit".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Stop and start the daemon again; only daemons started by this app can be restarted
+    pub async fn restart(&self, app_handle: AppHandle) -> Result<KernelProcessStatus, String> {
+        let was_running = {
+            let state = self.lock_state()?;
+            if state.attached {
+                return Err("Cannot restart a kernel daemon that was not started by this app".to_string());
+            }
+            state.running.is_some()
+        };
+
+        self.stop().await?;
+        if was_running {
+            self.lock_state()?.restarts += 1;
+        }
+        self.start(app_handle).await
+    }
+
+    /// Current daemon status, including a live health probe
+    pub async fn status(&self) -> Result<KernelProcessStatus, String> {
+        let healthy = self.is_healthy().await;
+        let state = self.lock_state()?;
+
+        let (mode, pid, started_at) = match &state.running {
+            Some(running) => (KernelMode::Managed, running.pid, Some(running.started_at.clone())),
+            None if state.starting => (KernelMode::Starting, None, None),
+            None if state.attached => (KernelMode::Attached, None, None),
+            None => (KernelMode::Stopped, None, None),
+        };
+
+        Ok(KernelProcessStatus {
+            mode,
+            pid,
+            started_at,
+            healthy,
+            restarts: state.restarts,
+            last_exit: state.last_exit.clone(),
+        })
+    }
+
+    /// Most recent output lines from the daemon, oldest first
+    pub fn recent_output(&self, limit: usize) -> Result<Vec<String>, String> {
+        let state = self.lock_state()?;
+        let skip = state.output.len().saturating_sub(limit);
+        Ok(state.output.iter().skip(skip).cloned().collect())
+    }
+
+    async fn is_healthy(&self) -> bool {
+        self.api.get::<serde_json::Value>("/v1/health").await.is_ok()
+    }
+
+    /// Probe a managed or attached daemon periodically and notify the webview when it
+    /// stops answering (`kernel:unresponsive`) or comes back (`kernel:recovered`)
+    fn watch_health(&self, app_handle: AppHandle) {
+        if self.health_watch.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let api = self.api.clone();
+        let state = self.state.clone();
+        let shutdown = self.shutdown.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let mut healthy = true;
+
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = tokio::time::sleep(HEALTH_INTERVAL) => {}
+                }
+
+                let supervised = match state.lock() {
+                    Ok(state) => state.running.is_some() || state.attached,
+                    Err(_) => break,
+                };
+                if !supervised {
+                    healthy = true;
+                    continue;
+                }
+
+                let responding = api.get::<serde_json::Value>("/v1/health").await.is_ok();
+                if responding == healthy {
+                    continue;
+                }
+                healthy = responding;
+
+                let event = if healthy { "kernel:recovered" } else { "kernel:unresponsive" };
+                if !healthy {
+                    log::warn!("Kernel daemon stopped answering health checks");
+                }
+                let payload = serde_json::json!({ "timestamp": chrono::Utc::now().to_rfc3339() });
+                let _ = app_handle.emit_all(event, payload);
+            }
+        });
+    }
+
+    /// Buffer daemon output and forward each line to the webview
+    fn forward_output<R>(&self, app_handle: AppHandle, stream: &'static str, reader: R)
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let state = self.state.clone();
+        let shutdown = self.shutdown.clone();
+
+        tauri::async_runtime::spawn(async move {
+            let mut lines = BufReader::new(reader).lines();
+
+            loop {
+                let line = tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    line = lines.next_line() => match line {
+                        Ok(Some(line)) => line,
+                        _ => break,
+                    },
+                };
+
+                if let Ok(mut state) = state.lock() {
+                    state.output.push_back(format!("[{}] {}", stream, line));
+                    while state.output.len() > MAX_OUTPUT_LINES {
+                        state.output.pop_front();
+                    }
+                }
+
+                let payload = serde_json::json!({ "stream": stream, "line": line });
+                let _ = app_handle.emit_all("kernel:output", payload);
+            }
+        });
+    }
+
+    fn lock_state(&self) -> Result<std::sync::MutexGuard<'_, SupervisorState>, String> {
+        self.state.lock()
+            .map_err(|_| "Failed to lock kernel supervisor state".to_string())
+    }
+}
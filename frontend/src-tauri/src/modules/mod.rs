@@ -1,12 +1,20 @@
 // Export all modules for use in main.rs
 pub mod cipher;
+pub mod conscience_review;
+pub mod correlation;
 pub mod ember;
+pub mod explain;
+pub mod ipc;
+pub mod kernel_api;
+pub mod kernel_events;
+pub mod kernel_supervisor;
+pub mod offline_cache;
 pub mod orchestrator;  // This is a wrapper that bridges to root orchestrator
 pub mod security;
+pub mod session_crypto;
 pub mod state;
-pub mod sse;
 
 // Re-export types that are commonly used
 pub use state::AppState;
-pub use sse::SseServer;
+pub use kernel_events::KernelEventClient;
 pub use orchestrator::OrchestratorModule;
\ No newline at end of file
@@ -0,0 +1,440 @@
+use std::{collections::{BTreeMap, VecDeque}, path::Path, sync::Mutex};
+use serde::{Serialize, Deserialize};
+use super::{
+    kernel_api::KernelApiClient,
+    kernel_events::KernelEvent,
+    session_crypto::{AtRestCipher, SessionCrypto},
+};
+
+/// Number of entries retained per category
+const MAX_ENTRIES: usize = 100;
+
+/// Number of recent alerts kept in memory for the status narrative
+const MAX_ALERTS: usize = 20;
+
+/// Cached data older than this is reported as stale
+const STALE_AFTER_SECS: i64 = 120;
+
+/// Categories of kernel state kept for offline use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheCategory {
+    Health,
+    Decisions,
+    Scans,
+}
+
+impl CacheCategory {
+    fn tree_name(&self) -> &'static str {
+        match self {
+            CacheCategory::Health => "health",
+            CacheCategory::Decisions => "decisions",
+            CacheCategory::Scans => "scans",
+        }
+    }
+
+    /// Key under which the last successful resync of this category is recorded
+    fn sync_key(&self) -> String {
+        format!("last_sync:{}", self.tree_name())
+    }
+
+    /// Kernel API path used to resync this category
+    fn resync_path(&self) -> &'static str {
+        match self {
+            CacheCategory::Health => "/v1/health",
+            CacheCategory::Decisions => "/v1/conscience/decisions?limit=50",
+            CacheCategory::Scans => "/v1/scans?limit=50",
+        }
+    }
+
+    /// Scans carry targets, so they are only fetched over the encrypted session
+    fn is_sealed(&self) -> bool {
+        matches!(self, CacheCategory::Scans)
+    }
+}
+
+/// A cached kernel payload with the time it was received
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedEntry {
+    pub data: serde_json::Value,
+    pub cached_at: String,
+    #[serde(default)]
+    pub age_seconds: i64,
+}
+
+/// Everything the UI needs to render while the kernel is unreachable
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CachedSnapshot {
+    pub online: bool,
+    pub stale: bool,
+    /// False when the cache only lives in memory and is lost on restart
+    pub persistent: bool,
+    /// Most recent successful resync of any category
+    pub last_sync: Option<String>,
+    pub synced_at: BTreeMap<CacheCategory, String>,
+    pub health: Option<CachedEntry>,
+    pub decisions: Vec<CachedEntry>,
+    pub scans: Vec<CachedEntry>,
+    pub alerts: Vec<CachedEntry>,
+}
+
+/// OfflineCache keeps recent health, decisions and scan results in a local
+/// sled database so the UI stays useful when the kernel daemon is down.
+/// Entries are encrypted at rest since decisions and scans carry targets and evidence.
+/// Recent alerts are only kept in memory.
+pub struct OfflineCache {
+    db: sled::Db,
+    cipher: AtRestCipher,
+    persistent: bool,
+    alerts: Mutex<VecDeque<CachedEntry>>,
+}
+
+impl OfflineCache {
+    /// Open the cache under the given directory
+    pub fn open(dir: &Path) -> Result<Self, String> {
+        let db = sled::open(dir.join("kernel-cache"))
+            .map_err(|e| format!("Failed to open offline cache: {}", e))?;
+        let cipher = AtRestCipher::load_or_create("kernel-cache", &dir.join("kernel-cache.key"))?;
+
+        Ok(Self::with(db, cipher, true))
+    }
+
+    /// Open a throwaway cache, used when the app data directory is unavailable
+    pub fn temporary() -> Result<Self, String> {
+        let db = sled::Config::new().temporary(true).open()
+            .map_err(|e| format!("Failed to open temporary offline cache: {}", e))?;
+        let cipher = AtRestCipher::ephemeral()?;
+
+        Ok(Self::with(db, cipher, false))
+    }
+
+    fn with(db: sled::Db, cipher: AtRestCipher, persistent: bool) -> Self {
+        Self {
+            db,
+            cipher,
+            persistent,
+            alerts: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Store a payload in the given category, trimming old entries
+    pub fn store(&self, category: CacheCategory, data: &serde_json::Value) -> Result<(), String> {
+        let tree = self.tree(category)?;
+        let value = self.encode(data)?;
+
+        // Monotonic big-endian ids keep the tree ordered oldest to newest
+        let id = self.next_id()?;
+        tree.insert(id.to_be_bytes(), value)
+            .map_err(|e| format!("Failed to write cache entry: {}", e))?;
+
+        trim(&tree)
+    }
+
+    /// Replace the cached list for a category with a fresh copy from the kernel.
+    /// `watermark` is an id taken before the list was fetched: entries below it are
+    /// replaced in one batch, while events recorded during the fetch are kept.
+    fn replace(&self, category: CacheCategory, watermark: u64, items: &[serde_json::Value]) -> Result<(), String> {
+        let tree = self.tree(category)?;
+        let mut batch = sled::Batch::default();
+
+        for key in tree.range(..watermark.to_be_bytes()).keys() {
+            let key = key.map_err(|e| format!("Failed to read cache: {}", e))?;
+            batch.remove(key);
+        }
+
+        // The list is newest first; keys `watermark ‖ index` sort oldest first,
+        // after everything replaced and before anything recorded since
+        for (index, item) in items.iter().rev().enumerate() {
+            let mut key = watermark.to_be_bytes().to_vec();
+            key.extend_from_slice(&(index as u32).to_be_bytes());
+            batch.insert(key, self.encode(item)?);
+        }
+
+        tree.apply_batch(batch)
+            .map_err(|e| format!("Failed to replace {} cache: {}", category.tree_name(), e))?;
+
+        trim(&tree)
+    }
+
+    /// Cache an event received from the kernel stream
+    pub fn record_event(&self, event: &KernelEvent) -> Result<(), String> {
+        let category = match event {
+            KernelEvent::HealthStatus(_) => CacheCategory::Health,
+            KernelEvent::ConscienceDecision(_) => CacheCategory::Decisions,
+            KernelEvent::ScanUpdate(_) => CacheCategory::Scans,
+            KernelEvent::Alert(payload) => return self.record_alert(payload),
+            // Deliberation progress is only useful live
+            KernelEvent::DecisionProgress(_) => return Ok(()),
+        };
+
+        self.store(category, event.payload())
+    }
+
+    /// Keep an alert in the in-memory ring buffer
+    fn record_alert(&self, data: &serde_json::Value) -> Result<(), String> {
+        let mut alerts = self.alerts.lock()
+            .map_err(|_| "Failed to lock recent alerts".to_string())?;
+
+        alerts.push_front(CachedEntry {
+            data: data.clone(),
+            cached_at: chrono::Utc::now().to_rfc3339(),
+            age_seconds: 0,
+        });
+        alerts.truncate(MAX_ALERTS);
+
+        Ok(())
+    }
+
+    /// Recent alerts, newest first
+    pub fn recent_alerts(&self) -> Result<Vec<CachedEntry>, String> {
+        let alerts = self.alerts.lock()
+            .map_err(|_| "Failed to lock recent alerts".to_string())?;
+        let now = chrono::Utc::now();
+
+        Ok(alerts.iter().cloned().map(|mut entry| {
+            if let Ok(cached_at) = chrono::DateTime::parse_from_rfc3339(&entry.cached_at) {
+                entry.age_seconds = (now - cached_at.with_timezone(&chrono::Utc)).num_seconds();
+            }
+            entry
+        }).collect())
+    }
+
+    /// Most recent entries in a category, newest first
+    pub fn recent(&self, category: CacheCategory, limit: usize) -> Result<Vec<CachedEntry>, String> {
+        let tree = self.tree(category)?;
+        let now = chrono::Utc::now();
+        let mut entries = Vec::new();
+
+        for item in tree.iter().rev() {
+            if entries.len() >= limit {
+                break;
+            }
+
+            let (_, value) = item.map_err(|e| format!("Failed to read cache: {}", e))?;
+
+            // Entries written under a previous key can no longer be read
+            let value = match self.cipher.decrypt(&value) {
+                Ok(value) => value,
+                Err(e) => {
+                    log::warn!("Skipping unreadable cache entry: {}", e);
+                    continue;
+                }
+            };
+            let mut entry: CachedEntry = serde_json::from_slice(&value)
+                .map_err(|e| format!("Failed to parse cache entry: {}", e))?;
+
+            if let Ok(cached_at) = chrono::DateTime::parse_from_rfc3339(&entry.cached_at) {
+                entry.age_seconds = (now - cached_at.with_timezone(&chrono::Utc)).num_seconds();
+            }
+
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+
+    /// Pull fresh state from the kernel, typically right after reconnecting.
+    /// Each category is synced on its own so one failing endpoint doesn't hold back the rest.
+    pub async fn resync(&self, api: &KernelApiClient, session: &SessionCrypto) -> Result<(), String> {
+        let mut failures = Vec::new();
+
+        for category in [CacheCategory::Health, CacheCategory::Decisions, CacheCategory::Scans] {
+            if let Err(e) = self.resync_category(category, api, session).await {
+                failures.push(format!("{}: {}", category.tree_name(), e));
+            }
+        }
+
+        self.db.flush_async().await
+            .map_err(|e| format!("Failed to flush offline cache: {}", e))?;
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Offline cache resync incomplete ({})", failures.join("; ")))
+        }
+    }
+
+    async fn resync_category(
+        &self,
+        category: CacheCategory,
+        api: &KernelApiClient,
+        session: &SessionCrypto,
+    ) -> Result<(), String> {
+        let watermark = self.next_id()?;
+        let data: serde_json::Value = if category.is_sealed() {
+            session.get_sealed(category.resync_path()).await?
+        } else {
+            api.get(category.resync_path()).await?
+        };
+
+        // The list replaces what was cached so repeated reconnects don't pile up copies
+        match data {
+            serde_json::Value::Array(items) if category != CacheCategory::Health => {
+                self.replace(category, watermark, &items)?
+            }
+            data => self.store(category, &data)?,
+        }
+
+        self.db.insert(category.sync_key(), chrono::Utc::now().to_rfc3339().as_bytes())
+            .map_err(|e| format!("Failed to record {} sync time: {}", category.tree_name(), e))?;
+
+        Ok(())
+    }
+
+    /// Build a snapshot of cached state with staleness indicators
+    pub fn snapshot(&self, online: bool) -> Result<CachedSnapshot, String> {
+        let mut synced_at = BTreeMap::new();
+        for category in [CacheCategory::Health, CacheCategory::Decisions, CacheCategory::Scans] {
+            let synced = self.db.get(category.sync_key())
+                .map_err(|e| format!("Failed to read sync time: {}", e))?
+                .and_then(|value| String::from_utf8(value.to_vec()).ok());
+            if let Some(synced) = synced {
+                synced_at.insert(category, synced);
+            }
+        }
+        let last_sync = synced_at.values()
+            .filter_map(|synced| chrono::DateTime::parse_from_rfc3339(synced).ok())
+            .max()
+            .map(|synced| synced.with_timezone(&chrono::Utc).to_rfc3339());
+
+        let health = self.recent(CacheCategory::Health, 1)?.into_iter().next();
+
+        // Data is stale when the kernel is unreachable or nothing has arrived recently
+        let fresh = matches!(&health, Some(entry) if entry.age_seconds <= STALE_AFTER_SECS);
+        let stale = !online || !fresh;
+
+        Ok(CachedSnapshot {
+            online,
+            stale,
+            persistent: self.persistent,
+            last_sync,
+            synced_at,
+            health,
+            decisions: self.recent(CacheCategory::Decisions, 50)?,
+            scans: self.recent(CacheCategory::Scans, 50)?,
+            alerts: self.recent_alerts()?,
+        })
+    }
+
+    /// Serialize and encrypt a payload as a new cache entry
+    fn encode(&self, data: &serde_json::Value) -> Result<Vec<u8>, String> {
+        let entry = CachedEntry {
+            data: data.clone(),
+            cached_at: chrono::Utc::now().to_rfc3339(),
+            age_seconds: 0,
+        };
+        let value = serde_json::to_vec(&entry)
+            .map_err(|e| format!("Failed to serialize cache entry: {}", e))?;
+
+        self.cipher.encrypt(&value)
+    }
+
+    fn next_id(&self) -> Result<u64, String> {
+        self.db.generate_id()
+            .map_err(|e| format!("Failed to allocate cache key: {}", e))
+    }
+
+    fn tree(&self, category: CacheCategory) -> Result<sled::Tree, String> {
+        self.db.open_tree(category.tree_name())
+            .map_err(|e| format!("Failed to open {} cache: {}", category.tree_name(), e))
+    }
+}
+
+/// Drop the oldest entries beyond MAX_ENTRIES
+fn trim(tree: &sled::Tree) -> Result<(), String> {
+    while tree.len() > MAX_ENTRIES {
+        if tree.pop_min().map_err(|e| format!("Failed to trim cache: {}", e))?.is_none() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn store_trims_to_max_entries() {
+        let cache = OfflineCache::temporary().unwrap();
+        for i in 0..MAX_ENTRIES + 5 {
+            cache.store(CacheCategory::Decisions, &serde_json::json!({ "seq": i })).unwrap();
+        }
+
+        let entries = cache.recent(CacheCategory::Decisions, MAX_ENTRIES * 2).unwrap();
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(entries.last().unwrap().data["seq"], 5);
+    }
+
+    #[test]
+    fn recent_returns_newest_first() {
+        let cache = OfflineCache::temporary().unwrap();
+        for i in 0..5 {
+            cache.store(CacheCategory::Scans, &serde_json::json!({ "seq": i })).unwrap();
+        }
+
+        let seqs: Vec<_> = cache.recent(CacheCategory::Scans, 3).unwrap()
+            .into_iter()
+            .map(|entry| entry.data["seq"].as_u64().unwrap())
+            .collect();
+        assert_eq!(seqs, [4, 3, 2]);
+    }
+
+    #[test]
+    fn recent_skips_unreadable_entries_before_applying_the_limit() {
+        let cache = OfflineCache::temporary().unwrap();
+        let rekeyed = OfflineCache::with(cache.db.clone(), AtRestCipher::ephemeral().unwrap(), false);
+        for i in 0..2 {
+            cache.store(CacheCategory::Decisions, &serde_json::json!({ "seq": i })).unwrap();
+        }
+        for i in 2..5 {
+            rekeyed.store(CacheCategory::Decisions, &serde_json::json!({ "seq": i })).unwrap();
+        }
+
+        let seqs: Vec<_> = cache.recent(CacheCategory::Decisions, 2).unwrap()
+            .into_iter()
+            .map(|entry| entry.data["seq"].as_u64().unwrap())
+            .collect();
+        assert_eq!(seqs, [1, 0]);
+    }
+
+    #[test]
+    fn replace_keeps_events_recorded_during_the_fetch() {
+        let cache = OfflineCache::temporary().unwrap();
+        cache.store(CacheCategory::Decisions, &serde_json::json!({ "seq": "stale" })).unwrap();
+
+        let watermark = cache.next_id().unwrap();
+        cache.store(CacheCategory::Decisions, &serde_json::json!({ "seq": "live" })).unwrap();
+        let fetched = [serde_json::json!({ "seq": "newer" }), serde_json::json!({ "seq": "older" })];
+        cache.replace(CacheCategory::Decisions, watermark, &fetched).unwrap();
+
+        let seqs: Vec<_> = cache.recent(CacheCategory::Decisions, 10).unwrap()
+            .into_iter()
+            .map(|entry| entry.data["seq"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(seqs, ["live", "newer", "older"]);
+    }
+
+    #[test]
+    fn recent_alerts_are_capped_newest_first() {
+        let cache = OfflineCache::temporary().unwrap();
+        for i in 0..MAX_ALERTS + 3 {
+            cache.record_event(&KernelEvent::Alert(serde_json::json!({ "seq": i }))).unwrap();
+        }
+
+        let alerts = cache.recent_alerts().unwrap();
+        assert_eq!(alerts.len(), MAX_ALERTS);
+        assert_eq!(alerts[0].data["seq"], MAX_ALERTS + 2);
+        assert_eq!(alerts[MAX_ALERTS - 1].data["seq"], 3);
+    }
+
+    #[test]
+    fn temporary_cache_is_reported_as_not_persistent() {
+        let snapshot = OfflineCache::temporary().unwrap().snapshot(false).unwrap();
+
+        assert!(!snapshot.persistent);
+        assert!(snapshot.stale);
+        assert!(snapshot.last_sync.is_none());
+    }
+}
@@ -0,0 +1,604 @@
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use ring::{aead, agreement, hkdf, hmac, rand};
+use ring::rand::SecureRandom;
+use serde::{de::DeserializeOwned, Serialize, Deserialize};
+use tokio::sync::{watch, Mutex, RwLock};
+use super::kernel_api::KernelApiClient;
+
+/// Context string bound into every derived session key
+const SESSION_INFO: &[u8] = b"phoenix-orch session v1";
+
+/// Context string prefixed to the handshake transcript the kernel signs
+const HANDSHAKE_CONTEXT: &[u8] = b"phoenix-orch handshake v1";
+
+/// Keychain service holding device keys
+const KEYCHAIN_SERVICE: &str = "phoenix-orch";
+
+/// Header carrying the session id on sealed requests and the event stream
+pub const SESSION_HEADER: &str = "X-Phoenix-Session";
+
+/// Which side sealed a payload. The direction is bound into the AAD so a request
+/// reflected back by the network can't be accepted as the kernel's response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    ToKernel,
+    FromKernel,
+}
+
+impl Direction {
+    fn aad(self, session_id: &str) -> Vec<u8> {
+        let label: &[u8] = match self {
+            Direction::ToKernel => b"c2s",
+            Direction::FromKernel => b"s2c",
+        };
+        [label, b":", session_id.as_bytes()].concat()
+    }
+}
+
+/// An AES-256-GCM sealed JSON payload exchanged with the kernel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SealedPayload {
+    pub session_id: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HandshakeRequest {
+    public_key: String,
+}
+
+/// The kernel signs the handshake with HMAC-SHA256 under the API token over
+/// `HANDSHAKE_CONTEXT || client public key || kernel public key || session id`
+#[derive(Debug, Serialize, Deserialize)]
+struct HandshakeResponse {
+    session_id: String,
+    public_key: String,
+    signature: String,
+}
+
+/// Session key negotiated with the kernel
+struct SessionKey {
+    id: String,
+    key: aead::LessSafeKey,
+    established_at: Instant,
+}
+
+/// The current session plus the one it replaced, kept so payloads sealed just
+/// before a rotation can still be opened
+#[derive(Default)]
+struct Sessions {
+    current: Option<Arc<SessionKey>>,
+    previous: Option<Arc<SessionKey>>,
+}
+
+impl Sessions {
+    fn find(&self, session_id: &str) -> Option<Arc<SessionKey>> {
+        [&self.current, &self.previous]
+            .into_iter()
+            .flatten()
+            .find(|session| session.id == session_id)
+            .cloned()
+    }
+}
+
+/// SessionCrypto negotiates an ephemeral X25519 session with the kernel API
+/// and seals sensitive payloads (keys, evidence, scan targets) in transit.
+/// The handshake is authenticated with the API token, and session keys are
+/// rotated once they reach the configured age.
+pub struct SessionCrypto {
+    api: KernelApiClient,
+    rotate_after: Duration,
+    sessions: RwLock<Sessions>,
+    rotation: Mutex<()>,
+    established: watch::Sender<Option<String>>,
+}
+
+impl SessionCrypto {
+    /// Create a new SessionCrypto instance
+    pub fn new(api: KernelApiClient, rotate_after: Duration) -> Self {
+        Self {
+            api,
+            rotate_after,
+            sessions: RwLock::new(Sessions::default()),
+            rotation: Mutex::new(()),
+            established: watch::channel(None).0,
+        }
+    }
+
+    /// How often session keys are rotated
+    pub fn rotate_after(&self) -> Duration {
+        self.rotate_after
+    }
+
+    /// Negotiate a fresh session key with the kernel, replacing the current one
+    pub async fn rotate(&self) -> Result<(), String> {
+        let _rotation = self.rotation.lock().await;
+        self.rotate_locked().await.map(|_| ())
+    }
+
+    /// Watch the id of the most recently established session, e.g. to re-announce
+    /// it on long-lived connections after a rotation
+    pub fn subscribe(&self) -> watch::Receiver<Option<String>> {
+        self.established.subscribe()
+    }
+
+    /// Whether a session is established or can be negotiated now
+    pub async fn is_available(&self) -> bool {
+        match self.current_session().await {
+            Ok(_) => true,
+            Err(e) => {
+                log::warn!("Kernel session unavailable: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Id of the current session, negotiating one if needed
+    pub async fn session_id(&self) -> Result<String, String> {
+        Ok(self.current_session().await?.id.clone())
+    }
+
+    /// Seal a JSON value under the current session, negotiating one if needed
+    pub async fn seal<T: Serialize>(&self, value: &T) -> Result<SealedPayload, String> {
+        let session = self.current_session().await?;
+        seal_with(&session, Direction::ToKernel, value)
+    }
+
+    /// Open a payload sealed by the kernel under the current or previous session
+    pub async fn open<T: DeserializeOwned>(&self, payload: &SealedPayload) -> Result<T, String> {
+        let session = self.sessions.read().await.find(&payload.session_id)
+            .ok_or_else(|| "Payload was sealed under an unknown session".to_string())?;
+
+        open_with(&session, Direction::FromKernel, payload)
+    }
+
+    /// POST a sealed body and open the sealed response with the same session key
+    pub async fn post_sealed<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T, String> {
+        let session = self.current_session().await?;
+        let sealed = seal_with(&session, Direction::ToKernel, body)?;
+        let response: SealedPayload = self.api
+            .post_with_header(path, SESSION_HEADER, &session.id, &sealed)
+            .await?;
+
+        open_with(&session, Direction::FromKernel, &response)
+    }
+
+    /// GET a resource the kernel returns sealed under the current session
+    pub async fn get_sealed<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+        let session = self.current_session().await?;
+        let response: SealedPayload = self.api
+            .get_with_header(path, SESSION_HEADER, &session.id)
+            .await?;
+
+        open_with(&session, Direction::FromKernel, &response)
+    }
+
+    /// Current session if it has not outlived the rotation interval
+    async fn live_session(&self) -> Option<Arc<SessionKey>> {
+        self.sessions.read().await.current.clone()
+            .filter(|session| session.established_at.elapsed() < self.rotate_after)
+    }
+
+    /// Current session, rotating first if it is missing or expired
+    async fn current_session(&self) -> Result<Arc<SessionKey>, String> {
+        if let Some(session) = self.live_session().await {
+            return Ok(session);
+        }
+
+        // Only one caller negotiates; the others pick up its session
+        let _rotation = self.rotation.lock().await;
+        if let Some(session) = self.live_session().await {
+            return Ok(session);
+        }
+
+        self.rotate_locked().await
+    }
+
+    /// Run the handshake; callers must hold the rotation lock
+    async fn rotate_locked(&self) -> Result<Arc<SessionKey>, String> {
+        let token = self.api.token()
+            .ok_or_else(|| "PHOENIX_API_TOKEN must be set to authenticate the kernel session".to_string())?;
+        if !self.api.is_secure_transport() {
+            return Err("Refusing to negotiate a kernel session over plain HTTP to a remote host; use https".to_string());
+        }
+
+        let rng = rand::SystemRandom::new();
+        let private_key = agreement::EphemeralPrivateKey::generate(&agreement::X25519, &rng)
+            .map_err(|_| "Failed to generate session key pair".to_string())?;
+        let public_key = private_key.compute_public_key()
+            .map_err(|_| "Failed to compute session public key".to_string())?;
+
+        let request = HandshakeRequest {
+            public_key: base64::encode(public_key.as_ref()),
+        };
+        let response: HandshakeResponse = self.api.post("/v1/session/handshake", &request).await?;
+
+        let peer_key = base64::decode(&response.public_key)
+            .map_err(|e| format!("Failed to decode kernel public key: {}", e))?;
+        let signature = base64::decode(&response.signature)
+            .map_err(|e| format!("Failed to decode handshake signature: {}", e))?;
+
+        // Reject a kernel key that was not signed with our token, e.g. one swapped in transit
+        verify_handshake(token, public_key.as_ref(), &peer_key, &response.session_id, &signature)?;
+
+        let peer_key = agreement::UnparsedPublicKey::new(&agreement::X25519, peer_key);
+        let key = agreement::agree_ephemeral(private_key, &peer_key, |shared_secret| {
+            derive_session_key(shared_secret, token.as_bytes(), response.session_id.as_bytes())
+        })
+        .map_err(|_| "Session key agreement failed".to_string())??;
+
+        let session = Arc::new(SessionKey {
+            id: response.session_id,
+            key,
+            established_at: Instant::now(),
+        });
+
+        {
+            let mut sessions = self.sessions.write().await;
+            sessions.previous = sessions.current.replace(session.clone());
+        }
+        self.established.send_replace(Some(session.id.clone()));
+
+        log::info!("Established encrypted kernel session");
+        Ok(session)
+    }
+}
+
+/// Seal a JSON value under a specific session
+fn seal_with<T: Serialize>(session: &SessionKey, direction: Direction, value: &T) -> Result<SealedPayload, String> {
+    let plaintext = serde_json::to_vec(value)
+        .map_err(|e| format!("Failed to serialize payload: {}", e))?;
+    let (nonce, ciphertext) = seal_bytes(&session.key, &direction.aad(&session.id), plaintext)?;
+
+    Ok(SealedPayload {
+        session_id: session.id.clone(),
+        nonce: base64::encode(nonce),
+        ciphertext: base64::encode(ciphertext),
+    })
+}
+
+/// Open a payload that must have been sealed under the given session and direction
+fn open_with<T: DeserializeOwned>(session: &SessionKey, direction: Direction, payload: &SealedPayload) -> Result<T, String> {
+    if payload.session_id != session.id {
+        return Err("Payload was sealed under an unknown session".to_string());
+    }
+
+    let nonce = base64::decode(&payload.nonce)
+        .map_err(|e| format!("Failed to decode nonce: {}", e))?;
+    let ciphertext = base64::decode(&payload.ciphertext)
+        .map_err(|e| format!("Failed to decode ciphertext: {}", e))?;
+    let plaintext = open_bytes(&session.key, &direction.aad(&session.id), &nonce, ciphertext)?;
+
+    serde_json::from_slice(&plaintext)
+        .map_err(|e| format!("Failed to parse decrypted payload: {}", e))
+}
+
+/// Bytes covered by the kernel's handshake signature
+fn handshake_transcript(client_key: &[u8], kernel_key: &[u8], session_id: &str) -> Vec<u8> {
+    [HANDSHAKE_CONTEXT, client_key, kernel_key, session_id.as_bytes()].concat()
+}
+
+/// Check the kernel's HMAC over the handshake transcript
+fn verify_handshake(
+    token: &str,
+    client_key: &[u8],
+    kernel_key: &[u8],
+    session_id: &str,
+    signature: &[u8],
+) -> Result<(), String> {
+    if kernel_key.len() != 32 {
+        return Err("Kernel public key has an invalid length".to_string());
+    }
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, token.as_bytes());
+    hmac::verify(&key, &handshake_transcript(client_key, kernel_key, session_id), signature)
+        .map_err(|_| "Kernel handshake signature is invalid".to_string())
+}
+
+/// Derive an AES-256-GCM key from an X25519 shared secret, salted with the API
+/// token so only holders of the token can derive it
+fn derive_session_key(shared_secret: &[u8], token: &[u8], session_id: &[u8]) -> Result<aead::LessSafeKey, String> {
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, token).extract(shared_secret);
+    let info = [SESSION_INFO, session_id];
+    let okm = prk.expand(&info, &aead::AES_256_GCM)
+        .map_err(|_| "Failed to expand session key".to_string())?;
+
+    Ok(aead::LessSafeKey::new(aead::UnboundKey::from(okm)))
+}
+
+/// Seal bytes with a random nonce, returning the nonce and ciphertext
+fn seal_bytes(key: &aead::LessSafeKey, aad: &[u8], mut data: Vec<u8>) -> Result<([u8; 12], Vec<u8>), String> {
+    let mut nonce = [0u8; 12];
+    rand::SystemRandom::new().fill(&mut nonce)
+        .map_err(|_| "Failed to generate nonce".to_string())?;
+
+    key.seal_in_place_append_tag(aead::Nonce::assume_unique_for_key(nonce), aead::Aad::from(aad), &mut data)
+        .map_err(|_| "Failed to encrypt payload".to_string())?;
+
+    Ok((nonce, data))
+}
+
+/// Open bytes sealed by `seal_bytes`
+fn open_bytes(key: &aead::LessSafeKey, aad: &[u8], nonce: &[u8], mut data: Vec<u8>) -> Result<Vec<u8>, String> {
+    let nonce = aead::Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| "Invalid nonce length".to_string())?;
+
+    let plaintext = key.open_in_place(nonce, aead::Aad::from(aad), &mut data)
+        .map_err(|_| "Failed to decrypt payload".to_string())?;
+
+    Ok(plaintext.to_vec())
+}
+
+/// AtRestCipher encrypts data kept on disk by the Tauri backend using a
+/// device key held in the OS keychain, away from the data it protects
+pub struct AtRestCipher {
+    key: aead::LessSafeKey,
+}
+
+impl AtRestCipher {
+    /// Load the device key for `account` from the OS keychain, creating it on first use.
+    /// Without a usable keychain (e.g. headless Linux) the key is kept in `fallback_key_file`,
+    /// readable only by the current user.
+    pub fn load_or_create(account: &str, fallback_key_file: &Path) -> Result<Self, String> {
+        match keychain_key(account) {
+            Ok(key_bytes) => Self::from_key(&key_bytes),
+            Err(e) => {
+                log::warn!("{}; keeping the device key in {}", e, fallback_key_file.display());
+                Self::from_key(&file_key(fallback_key_file)?)
+            }
+        }
+    }
+
+    /// Create a cipher with a random key that only lives in memory
+    pub fn ephemeral() -> Result<Self, String> {
+        Self::from_key(&random_key()?)
+    }
+
+    /// Encrypt bytes, prefixing the ciphertext with its nonce
+    pub fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        let (nonce, ciphertext) = seal_bytes(&self.key, &[], data.to_vec())?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Ok(sealed)
+    }
+
+    /// Decrypt bytes produced by `encrypt`
+    pub fn decrypt(&self, sealed: &[u8]) -> Result<Vec<u8>, String> {
+        if sealed.len() < 12 {
+            return Err("Encrypted cache entry is truncated".to_string());
+        }
+
+        let (nonce, ciphertext) = sealed.split_at(12);
+        open_bytes(&self.key, &[], nonce, ciphertext.to_vec())
+    }
+
+    fn from_key(key_bytes: &[u8]) -> Result<Self, String> {
+        let key = aead::UnboundKey::new(&aead::AES_256_GCM, key_bytes)
+            .map_err(|_| "Invalid cache key".to_string())?;
+
+        Ok(Self { key: aead::LessSafeKey::new(key) })
+    }
+}
+
+/// Device key stored in the OS keychain, created on first use
+fn keychain_key(account: &str) -> Result<Vec<u8>, String> {
+    let entry = keyring::Entry::new(KEYCHAIN_SERVICE, account)
+        .map_err(|e| format!("Failed to open keychain entry: {}", e))?;
+
+    match entry.get_password() {
+        Ok(encoded) => base64::decode(encoded)
+            .map_err(|e| format!("Failed to decode keychain device key: {}", e)),
+        Err(keyring::Error::NoEntry) => {
+            let key_bytes = random_key()?;
+            entry.set_password(&base64::encode(key_bytes))
+                .map_err(|e| format!("Failed to store device key in the keychain: {}", e))?;
+            Ok(key_bytes.to_vec())
+        }
+        Err(e) => Err(format!("Failed to read device key from the keychain: {}", e)),
+    }
+}
+
+/// Device key stored in a file only the current user can read, created on first use
+fn file_key(path: &Path) -> Result<Vec<u8>, String> {
+    match std::fs::read(path) {
+        Ok(key_bytes) => return Ok(key_bytes),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(format!("Failed to read device key file: {}", e)),
+    }
+
+    let key_bytes = random_key()?;
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path)
+        .map_err(|e| format!("Failed to create device key file: {}", e))?;
+    std::io::Write::write_all(&mut file, &key_bytes)
+        .map_err(|e| format!("Failed to write device key file: {}", e))?;
+
+    Ok(key_bytes.to_vec())
+}
+
+fn random_key() -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    rand::SystemRandom::new().fill(&mut key)
+        .map_err(|_| "Failed to generate cache key".to_string())?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> aead::LessSafeKey {
+        let key = aead::UnboundKey::new(&aead::AES_256_GCM, &random_key().unwrap()).unwrap();
+        aead::LessSafeKey::new(key)
+    }
+
+    fn test_session(id: &str) -> Arc<SessionKey> {
+        Arc::new(SessionKey {
+            id: id.to_string(),
+            key: test_key(),
+            established_at: Instant::now(),
+        })
+    }
+
+    fn test_crypto() -> SessionCrypto {
+        let api = KernelApiClient::new("http://127.0.0.1:1", Some("token".to_string()));
+        SessionCrypto::new(api, Duration::from_secs(60))
+    }
+
+    #[test]
+    fn seal_bytes_round_trip() {
+        let key = test_key();
+        let (nonce, ciphertext) = seal_bytes(&key, b"session", b"scan target".to_vec()).unwrap();
+
+        let plaintext = open_bytes(&key, b"session", &nonce, ciphertext).unwrap();
+        assert_eq!(plaintext, b"scan target");
+    }
+
+    #[test]
+    fn open_bytes_rejects_tampered_input() {
+        let key = test_key();
+        let (nonce, mut ciphertext) = seal_bytes(&key, b"session", b"scan target".to_vec()).unwrap();
+        ciphertext[0] ^= 0x01;
+
+        assert!(open_bytes(&key, b"session", &nonce, ciphertext).is_err());
+    }
+
+    #[test]
+    fn open_bytes_rejects_wrong_session() {
+        let key = test_key();
+        let (nonce, ciphertext) = seal_bytes(&key, b"session-a", b"scan target".to_vec()).unwrap();
+
+        assert!(open_bytes(&key, b"session-b", &nonce, ciphertext).is_err());
+    }
+
+    #[test]
+    fn open_bytes_rejects_truncated_input() {
+        let key = test_key();
+        let (nonce, ciphertext) = seal_bytes(&key, b"session", b"scan target".to_vec()).unwrap();
+
+        assert!(open_bytes(&key, b"session", &nonce[..8], ciphertext.clone()).is_err());
+        assert!(open_bytes(&key, b"session", &nonce, ciphertext[..ciphertext.len() - 1].to_vec()).is_err());
+        assert!(open_bytes(&key, b"session", &nonce, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn at_rest_round_trip() {
+        let cipher = AtRestCipher::ephemeral().unwrap();
+        let sealed = cipher.encrypt(b"cached decision").unwrap();
+
+        assert_ne!(&sealed[12..], b"cached decision");
+        assert_eq!(cipher.decrypt(&sealed).unwrap(), b"cached decision");
+    }
+
+    #[test]
+    fn at_rest_rejects_truncated_input() {
+        let cipher = AtRestCipher::ephemeral().unwrap();
+        let sealed = cipher.encrypt(b"cached decision").unwrap();
+
+        assert!(cipher.decrypt(&sealed[..11]).is_err());
+        assert!(cipher.decrypt(&sealed[..12]).is_err());
+        assert!(cipher.decrypt(&sealed[..sealed.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn at_rest_rejects_tampered_input() {
+        let cipher = AtRestCipher::ephemeral().unwrap();
+        let mut sealed = cipher.encrypt(b"cached decision").unwrap();
+        let last = sealed.len() - 1;
+        sealed[last] ^= 0x01;
+
+        assert!(cipher.decrypt(&sealed).is_err());
+    }
+
+    #[test]
+    fn at_rest_rejects_another_key() {
+        let sealed = AtRestCipher::ephemeral().unwrap().encrypt(b"cached decision").unwrap();
+
+        assert!(AtRestCipher::ephemeral().unwrap().decrypt(&sealed).is_err());
+    }
+
+    #[test]
+    fn handshake_signature_is_verified() {
+        let client_key = [1u8; 32];
+        let kernel_key = [2u8; 32];
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"token");
+        let signature = hmac::sign(&key, &handshake_transcript(&client_key, &kernel_key, "session"));
+
+        assert!(verify_handshake("token", &client_key, &kernel_key, "session", signature.as_ref()).is_ok());
+        assert!(verify_handshake("other", &client_key, &kernel_key, "session", signature.as_ref()).is_err());
+        assert!(verify_handshake("token", &client_key, &[3u8; 32], "session", signature.as_ref()).is_err());
+        assert!(verify_handshake("token", &client_key, &kernel_key, "other", signature.as_ref()).is_err());
+    }
+
+    #[tokio::test]
+    async fn open_accepts_payloads_from_the_previous_session() {
+        let crypto = test_crypto();
+        let previous = test_session("previous");
+        let payload = seal_with(&previous, Direction::FromKernel, &serde_json::json!({ "target": "10.0.0.1" })).unwrap();
+
+        {
+            let mut sessions = crypto.sessions.write().await;
+            sessions.previous = Some(previous);
+            sessions.current = Some(test_session("current"));
+        }
+
+        let opened: serde_json::Value = crypto.open(&payload).await.unwrap();
+        assert_eq!(opened["target"], "10.0.0.1");
+    }
+
+    #[tokio::test]
+    async fn open_rejects_unknown_sessions() {
+        let crypto = test_crypto();
+        let payload = seal_with(&test_session("stale"), Direction::FromKernel, &serde_json::json!({})).unwrap();
+        crypto.sessions.write().await.current = Some(test_session("current"));
+
+        assert!(crypto.open::<serde_json::Value>(&payload).await.is_err());
+    }
+
+    #[test]
+    fn open_with_rejects_tampered_payloads() {
+        let session = test_session("current");
+        let mut payload = seal_with(&session, Direction::FromKernel, &serde_json::json!({ "key": "value" })).unwrap();
+        let mut ciphertext = base64::decode(&payload.ciphertext).unwrap();
+        ciphertext[0] ^= 0x01;
+        payload.ciphertext = base64::encode(ciphertext);
+
+        assert!(open_with::<serde_json::Value>(&session, Direction::FromKernel, &payload).is_err());
+    }
+
+    #[test]
+    fn open_with_rejects_reflected_requests() {
+        let session = test_session("current");
+        let request = seal_with(&session, Direction::ToKernel, &serde_json::json!({ "key": "value" })).unwrap();
+
+        assert!(open_with::<serde_json::Value>(&session, Direction::FromKernel, &request).is_err());
+    }
+
+    #[test]
+    fn file_key_is_created_once_and_private() {
+        let dir = std::env::temp_dir().join(format!("phoenix-key-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("kernel-cache.key");
+
+        let created = file_key(&path).unwrap();
+        assert_eq!(file_key(&path).unwrap(), created);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
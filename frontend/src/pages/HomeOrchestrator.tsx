@@ -1,9 +1,10 @@
 import React, { useEffect, useState, useRef } from 'react';
 import { Canvas } from '@react-three/fiber';
+import { listen } from '@tauri-apps/api/event';
 import { OrbitControls, useGLTF, Stars } from '@react-three/drei';
 import { useHomeStore } from '../stores/homeStore';
 import {
-  initializeSseConnection,
+  connectKernelEvents,
   executeEmberOperation
 } from '../tauri/invoke';
 
@@ -415,11 +416,16 @@ const HomeOrchestrator = () => {
   
   // Initialize backend connection
   useEffect(() => {
+    // Track the kernel event stream as it connects and drops
+    const unlistenConnection = listen<{ connected: boolean }>('kernel:connection', (event) => {
+      setConnected(event.payload.connected);
+    });
+    
     const setupConnections = async () => {
       try {
-        // Initialize SSE connection for events
-        await initializeSseConnection();
-        setConnected(true);
+        // Make sure the kernel event stream is running
+        const kernelConnected = await connectKernelEvents();
+        setConnected(kernelConnected);
         
         // Setup WebSocket for real-time updates
         // Replace with your actual WebSocket endpoint
@@ -461,6 +467,7 @@ const HomeOrchestrator = () => {
     
     // Cleanup function
     return () => {
+      unlistenConnection.then(unsubscribe => unsubscribe());
       if (websocketRef.current) {
         websocketRef.current.close();
       }
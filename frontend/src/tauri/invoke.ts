@@ -35,15 +35,35 @@ export interface SystemTelemetry {
   storage_pb: number;
 }
 
-// Generic invoke function with error handling and context preservation
+// Commands whose arguments carry keys or secrets and must never be logged
+const SENSITIVE_COMMANDS = new Set([
+  'encrypt_data',
+  'decrypt_data',
+  'sync_secure_state',
+  'fetch_secure_state',
+]);
+
+function describeArgs(command: string, args?: Record<string, unknown>): string {
+  if (!args) {
+    return 'none';
+  }
+  return SENSITIVE_COMMANDS.has(command) ? '[redacted]' : JSON.stringify(args);
+}
+
+// Generic invoke function with error handling and context preservation.
+// Every call gets a correlation id that the kernel logs, echoes in error messages and
+// attaches to events the action triggers; commands that don't take one ignore it.
 async function invokeCommand<T>(command: string, args?: Record<string, unknown>): Promise<T> {
+  const correlationId = crypto.randomUUID();
+  
   try {
-    return await invoke<T>(command, args);
+    return await invoke<T>(command, { ...args, correlationId });
   } catch (error) {
     // Preserve full error context
     const errorContext = {
       command,
-      args: args ? JSON.stringify(args) : 'none',
+      correlationId,
+      args: describeArgs(command, args),
       error: error instanceof Error ? {
         message: error.message,
         stack: error.stack,
@@ -66,9 +86,77 @@ async function invokeCommand<T>(command: string, args?: Record<string, unknown>)
   }
 }
 
-// SSE Connection
-export async function initializeSseConnection(): Promise<void> {
-  return invokeCommand('initialize_sse_connection', {});
+// Kernel event stream (events arrive as `kernel:*` Tauri events)
+export async function connectKernelEvents(): Promise<boolean> {
+  return invokeCommand('connect_kernel_events', {});
+}
+
+// Kernel daemon supervision (output arrives as `kernel:output`, exits as `kernel:exited` or
+// `kernel:crashed`, and a hung daemon as `kernel:unresponsive` until `kernel:recovered`)
+export interface KernelProcessStatus {
+  mode: 'managed' | 'attached' | 'starting' | 'stopped';
+  pid: number | null;
+  started_at: string | null;
+  healthy: boolean;
+  restarts: number;
+  last_exit: string | null;
+}
+
+export async function startKernel(): Promise<KernelProcessStatus> {
+  return invokeCommand('start_kernel', {});
+}
+
+export async function stopKernel(): Promise<KernelProcessStatus> {
+  return invokeCommand('stop_kernel', {});
+}
+
+export async function restartKernel(): Promise<KernelProcessStatus> {
+  return invokeCommand('restart_kernel', {});
+}
+
+export async function getKernelStatus(): Promise<KernelProcessStatus> {
+  return invokeCommand('get_kernel_status', {});
+}
+
+export async function getKernelOutput(limit?: number): Promise<string[]> {
+  return invokeCommand('get_kernel_output', { limit });
+}
+
+// Offline cache (last known kernel state while the daemon is unreachable)
+export interface CachedEntry {
+  data: unknown;
+  cached_at: string;
+  age_seconds: number;
+}
+
+export interface CachedSnapshot {
+  online: boolean;
+  stale: boolean;
+  // False when the cache only lives in memory and is lost on restart
+  persistent: boolean;
+  // Most recent successful resync of any category, and of each one
+  last_sync: string | null;
+  synced_at: Partial<Record<'health' | 'decisions' | 'scans', string>>;
+  health: CachedEntry | null;
+  decisions: CachedEntry[];
+  scans: CachedEntry[];
+  // Recent `kernel:alert` payloads, kept in memory only
+  alerts: CachedEntry[];
+}
+
+export async function getCachedState(): Promise<CachedSnapshot> {
+  return invokeCommand('get_cached_state', {});
+}
+
+// System narrative (kernel /v1/explain, or a local summary while offline)
+export interface SystemExplanation {
+  narrative: string;
+  source: 'kernel' | 'local';
+  generated_at: string;
+}
+
+export async function explainSystemState(): Promise<SystemExplanation> {
+  return invokeCommand('explain_system_state', {});
 }
 
 // Cipher API
@@ -84,6 +172,15 @@ export async function decryptData(encryptedData: string, key: string): Promise<s
   return invokeCommand('decrypt_data', { encrypted_data: encryptedData, key });
 }
 
+// Encrypted state sync (sealed under the kernel session key)
+export async function syncSecureState(key: string, value: unknown): Promise<unknown> {
+  return invokeCommand('sync_secure_state', { key, value });
+}
+
+export async function fetchSecureState(key: string): Promise<unknown> {
+  return invokeCommand('fetch_secure_state', { key });
+}
+
 // Ember Unit API
 export async function activateEmberUnit(parameters: string): Promise<string> {
   return invokeCommand('activate_ember_unit', { parameters });
@@ -98,6 +195,47 @@ export async function validateMemoryIntegrity(): Promise<boolean> {
   return invokeCommand('validate_memory_integrity', {});
 }
 
+// Conscience review queue API
+export interface PendingReview {
+  id: string;
+  action: string;
+  requested_at: string;
+  reason?: string;
+}
+
+export interface ComponentReasoning {
+  component: string;
+  approve: boolean;
+  confidence: number;
+  reasoning: string;
+}
+
+export interface ReviewReasoning {
+  id: string;
+  action: string;
+  components: ComponentReasoning[];
+  consensus_approve: boolean;
+  consensus_confidence: number;
+  violated_constraints: string[];
+}
+
+export async function listPendingReviews(): Promise<PendingReview[]> {
+  return invokeCommand('list_pending_reviews', {});
+}
+
+export async function getReviewReasoning(reviewId: string): Promise<ReviewReasoning> {
+  return invokeCommand('get_review_reasoning', { reviewId });
+}
+
+// The reviewer is recorded as the OS user running the app
+export async function approveReview(reviewId: string, notes?: string): Promise<unknown> {
+  return invokeCommand('approve_review', { reviewId, notes });
+}
+
+export async function rejectReview(reviewId: string, notes?: string): Promise<unknown> {
+  return invokeCommand('reject_review', { reviewId, notes });
+}
+
 // Health API
 export async function getHealthStatus(): Promise<HealthResponse> {
   return invokeCommand('get_health_status', {});
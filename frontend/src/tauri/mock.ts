@@ -8,33 +8,124 @@ import { ChatRequest } from './invoke';
 // API base URL with correct port (5001 as per requirements)
 export const API_BASE = 'http://localhost:5001';
 
-// Mock SSE connection for development
-let sseConnection: EventSource | null = null;
+// Mock kernel event stream for development
+let kernelSocket: WebSocket | null = null;
+
+// Mock encrypted state store, keyed like the kernel's secure state
+const mockSecureState = new Map<string, unknown>();
+
+// Mock kernel daemon supervisor state
+let mockKernel = {
+  mode: 'stopped',
+  pid: null as number | null,
+  started_at: null as string | null,
+  restarts: 0,
+};
+
+function mockKernelStatus() {
+  return {
+    ...mockKernel,
+    healthy: mockKernel.mode !== 'stopped',
+    last_exit: null,
+  };
+}
 
 // Mock implementation of invoke function
 export async function invoke<T>(command: string, args?: any): Promise<T> {
   console.log(`[Tauri Mock] invoke: ${command}`, args);
   
   switch (command) {
-    // SSE Connection
-    case 'initialize_sse_connection': {
-      if (!sseConnection) {
-        sseConnection = new EventSource(`${API_BASE}/events`);
+    // Kernel event stream
+    case 'connect_kernel_events': {
+      if (!kernelSocket) {
+        kernelSocket = new WebSocket(`${API_BASE.replace(/^http/, 'ws')}/ws/events`);
         
-        sseConnection.onopen = () => {
-          console.log('[SSE] Connection established');
+        kernelSocket.onopen = () => {
+          console.log('[Kernel] Event stream connected');
         };
         
-        sseConnection.onerror = (error: Event) => {
-          console.error('[SSE] Connection error:', error);
+        kernelSocket.onerror = (error: Event) => {
+          console.error('[Kernel] Event stream error:', error);
         };
         
-        // Set up default event listeners
-        sseConnection.addEventListener('health_status', (event: MessageEvent) => {
-          console.log('[SSE] Health status:', event.data);
+        kernelSocket.onmessage = (event: MessageEvent) => {
+          console.log('[Kernel] Event:', event.data);
+        };
+      }
+      
+      // Wait for a fresh socket to finish connecting before reporting its state
+      if (kernelSocket.readyState === WebSocket.CONNECTING) {
+        const socket = kernelSocket;
+        await new Promise<void>((resolve) => {
+          socket.addEventListener('open', () => resolve(), { once: true });
+          socket.addEventListener('error', () => resolve(), { once: true });
+          socket.addEventListener('close', () => resolve(), { once: true });
         });
       }
-      return undefined as unknown as T;
+      return (kernelSocket?.readyState === WebSocket.OPEN) as unknown as T;
+    }
+    
+    // Kernel daemon supervision
+    case 'start_kernel': {
+      if (mockKernel.mode !== 'stopped') {
+        throw new Error('Kernel daemon is already running');
+      }
+      mockKernel = { ...mockKernel, mode: 'managed', pid: 4242, started_at: new Date().toISOString() };
+      return mockKernelStatus() as unknown as T;
+    }
+    
+    case 'stop_kernel': {
+      mockKernel = { ...mockKernel, mode: 'stopped', pid: null, started_at: null };
+      return mockKernelStatus() as unknown as T;
+    }
+    
+    case 'restart_kernel': {
+      mockKernel = {
+        mode: 'managed',
+        pid: 4242,
+        started_at: new Date().toISOString(),
+        restarts: mockKernel.restarts + (mockKernel.mode === 'managed' ? 1 : 0),
+      };
+      return mockKernelStatus() as unknown as T;
+    }
+    
+    case 'get_kernel_status': {
+      return mockKernelStatus() as unknown as T;
+    }
+    
+    case 'get_kernel_output': {
+      if (mockKernel.mode === 'stopped') {
+        return [] as unknown as T;
+      }
+      return [`[stdout] phoenix-core (mock) listening on ${API_BASE}`] as unknown as T;
+    }
+    
+    // Offline cache
+    case 'get_cached_state': {
+      const online = kernelSocket?.readyState === WebSocket.OPEN;
+      return {
+        online,
+        stale: !online,
+        persistent: false,
+        last_sync: null,
+        synced_at: {},
+        health: null,
+        decisions: [],
+        scans: [],
+        alerts: []
+      } as unknown as T;
+    }
+    
+    // System narrative
+    case 'explain_system_state': {
+      const online = kernelSocket?.readyState === WebSocket.OPEN;
+      return {
+        narrative: online
+          ? 'The kernel daemon is connected. Ember Unit is inactive. The orchestrator is running. (mock)'
+          : 'The kernel daemon is unreachable and no cached state is available yet. (mock)',
+        source: 'local',
+        generated_at: new Date().toISOString()
+      } as unknown as T;
     }
     
     // Cipher API
@@ -71,6 +162,27 @@ export async function invoke<T>(command: string, args?: any): Promise<T> {
       }
     }
     
+    // Encrypted state sync
+    case 'sync_secure_state': {
+      const { key, value } = args;
+      if (!key) {
+        throw new Error('Key cannot be empty');
+      }
+      mockSecureState.set(key, value);
+      return { key, stored: true } as unknown as T;
+    }
+    
+    case 'fetch_secure_state': {
+      const { key } = args;
+      if (!key) {
+        throw new Error('Key cannot be empty');
+      }
+      if (!mockSecureState.has(key)) {
+        throw new Error(`No secure state stored under ${key} (mock)`);
+      }
+      return mockSecureState.get(key) as unknown as T;
+    }
+    
     // Ember Unit API
     case 'activate_ember_unit': {
       return JSON.stringify({
@@ -97,6 +209,46 @@ export async function invoke<T>(command: string, args?: any): Promise<T> {
       return true as unknown as T;
     }
     
+    // Conscience review queue
+    case 'list_pending_reviews': {
+      return [
+        {
+          id: 'mock-review-1',
+          action: 'Run network scan against 10.0.0.0/24',
+          requested_at: new Date().toISOString(),
+          reason: 'Super-Ego confidence below review threshold'
+        }
+      ] as unknown as T;
+    }
+    
+    case 'get_review_reasoning': {
+      const { reviewId } = args;
+      return {
+        id: reviewId,
+        action: 'Run network scan against 10.0.0.0/24',
+        components: [
+          { component: 'id', approve: true, confidence: 0.9, reasoning: 'Mock: scan is within the engagement goal' },
+          { component: 'ego', approve: true, confidence: 0.7, reasoning: 'Mock: scope matches the engagement' },
+          { component: 'super_ego', approve: false, confidence: 0.55, reasoning: 'Mock: target ownership unconfirmed' }
+        ],
+        consensus_approve: false,
+        consensus_confidence: 0.55,
+        violated_constraints: []
+      } as unknown as T;
+    }
+    
+    case 'approve_review':
+    case 'reject_review': {
+      const { reviewId, notes } = args;
+      return {
+        id: reviewId,
+        approved: command === 'approve_review',
+        reviewer: 'mock-user',
+        notes: notes ?? null,
+        timestamp: new Date().toISOString()
+      } as unknown as T;
+    }
+    
     // Health API
     case 'get_health_status': {
       return {
@@ -133,10 +285,10 @@ export async function invoke<T>(command: string, args?: any): Promise<T> {
   }
 }
 
-// Cleanup function to close SSE connection when needed
+// Cleanup function to close the kernel event stream when needed
 export function cleanup() {
-  if (sseConnection) {
-    sseConnection.close();
-    sseConnection = null;
+  if (kernelSocket) {
+    kernelSocket.close();
+    kernelSocket = null;
   }
 }
\ No newline at end of file
@@ -105,8 +105,8 @@ Object.defineProperty(window, 'matchMedia', {
 // Mock Tauri API
 vi.mock('@tauri-apps/api/tauri', () => ({
   invoke: vi.fn().mockImplementation((command, args) => {
-    if (command === 'initialize_sse_connection') {
-      return Promise.resolve({ success: true });
+    if (command === 'connect_kernel_events') {
+      return Promise.resolve(true);
     }
     
     if (command === 'execute_ember_operation') {
@@ -137,6 +137,11 @@ vi.mock('@tauri-apps/api/tauri', () => ({
   })
 }));
 
+// Mock Tauri event API
+vi.mock('@tauri-apps/api/event', () => ({
+  listen: vi.fn().mockResolvedValue(() => {})
+}));
+
 // Import components after mocks
 import HomeOrchestrator from '../src/pages/HomeOrchestrator';
 import { useHomeStore } from '../src/stores/homeStore';